@@ -1,13 +1,29 @@
-use crate::Location;
-use std::fmt::{self, Display, Formatter, Write};
+use crate::{
+    ActivitySpanError, InvalidNameError, LessonError, Location, Period, PeriodRange, RangedU8,
+    TimeSlot,
+};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter, Write};
+use core::iter;
+use core::ops::Deref;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize};
 
 /// A subject/course which a student can undertake.
 ///
 /// # Remarks
 ///
 /// The `name` of the `Subject` must be an ASCII string with a length in
-/// the range `1..=16`.
+/// the range `1..=16` (as the `name` must be ASCII, its length in characters
+/// is the same as its length in bytes).
+///
+/// Leading and trailing whitespace is removed from the `name` before it is
+/// validated (e.g., `" Maths "` and `"Maths"` are the same `Subject`), but
+/// whitespace within the `name` is preserved as is (e.g., `"Further  Maths"`
+/// is not the same `Subject` as `"Further Maths"`).
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(transparent))]
 pub struct Subject(String);
 
 impl Subject {
@@ -15,27 +31,74 @@ impl Subject {
     ///
     /// Returns [`Some(Subject)`](Some) if `name` is an ASCII string with a
     /// length in the range `1..=16`, and [`None`] otherwise.
+    ///
+    /// *See [`Subject::try_from`] for the reason a `name` is invalid*.
     pub fn new(name: String) -> Option<Self> {
-        // Check the name's validity
-        if name.len() < 1 || name.len() > 16 || !name.is_ascii() {
-            None
-        } else {
-            Some(Self(name))
-        }
+        Self::try_from(name).ok()
     }
 
     /// Retrieve the name of the `Subject`.
-    pub fn name(&self) -> &String {
+    pub fn name(&self) -> &str {
         &self.0
     }
 }
 
+impl TryFrom<String> for Subject {
+    type Error = InvalidNameError;
+
+    /// Creates a new `Subject`, returning the reason the `name` is invalid if
+    /// it is not an ASCII string with a length in the range `1..=16` (once
+    /// leading and trailing whitespace is removed).
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        // Only reallocate if there is whitespace to remove
+        let trimmed = name.trim();
+        let name = if trimmed.len() == name.len() {
+            name
+        } else {
+            trimmed.to_string()
+        };
+
+        validate_name(&name, 16)?;
+        Ok(Self(name))
+    }
+}
+
+impl AsRef<str> for Subject {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl Deref for Subject {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.name()
+    }
+}
+
 impl Display for Subject {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Subject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Re-run the validation such that an invalid `Subject` cannot be
+        // constructed from an invalid payload
+        Self::new(String::deserialize(deserializer)?).ok_or_else(|| {
+            de::Error::custom(
+                "a subject name must be an ASCII string with a length in the range 1..=16",
+            )
+        })
+    }
+}
+
 /// The class for a particular [`Lesson`].
 ///
 /// In some cases, students may have multiple teachers for a particular
@@ -49,10 +112,12 @@ impl Display for Subject {
 /// # Remarks
 ///
 /// The class reference must be an ASCII string with a length in the range
-/// `1..=32`.
+/// `1..=32` (as the reference must be ASCII, its length in characters is the
+/// same as its length in bytes).
 ///
 /// [`Lesson`]: Activity::Lesson
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(transparent))]
 pub struct Class(String);
 
 impl Class {
@@ -61,31 +126,104 @@ impl Class {
     /// [`Some(Class)`](Some) is returned if `reference` is a valid ASCII
     /// string with a length in the range `1..=32`, otherwise [`None`] is
     /// returned.
+    ///
+    /// *See [`Class::try_from`] for the reason a `reference` is invalid*.
     pub fn new(reference: String) -> Option<Self> {
-        // Check the reference's validity
-        if reference.len() < 1 || reference.len() > 32 || !reference.is_ascii() {
-            None
-        } else {
-            Some(Self(reference))
-        }
+        Self::try_from(reference).ok()
     }
 
     /// Retrieves the reference of the `Class`.
-    pub fn reference(&self) -> &String {
+    pub fn reference(&self) -> &str {
         &self.0
     }
 }
 
+impl TryFrom<String> for Class {
+    type Error = InvalidNameError;
+
+    /// Creates a new `Class`, returning the reason the `reference` is invalid
+    /// if it is not an ASCII string with a length in the range `1..=32`.
+    fn try_from(reference: String) -> Result<Self, Self::Error> {
+        validate_name(&reference, 32)?;
+        Ok(Self(reference))
+    }
+}
+
+impl AsRef<str> for Class {
+    fn as_ref(&self) -> &str {
+        self.reference()
+    }
+}
+
+impl Deref for Class {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.reference()
+    }
+}
+
 impl Display for Class {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Class {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Re-run the validation such that an invalid `Class` cannot be
+        // constructed from an invalid payload
+        Self::new(String::deserialize(deserializer)?).ok_or_else(|| {
+            de::Error::custom(
+                "a class reference must be an ASCII string with a length in the range 1..=32",
+            )
+        })
+    }
+}
+
+/// Checks that `name` is an ASCII string with a length in the range
+/// `1..=max_len`.
+fn validate_name(name: &str, max_len: usize) -> Result<(), InvalidNameError> {
+    // The ASCII check precedes the length check as `len` counts bytes, which
+    // is only equal to the number of characters for ASCII strings
+    if name.is_empty() {
+        Err(InvalidNameError::Empty)
+    } else if !name.is_ascii() {
+        Err(InvalidNameError::NonAscii)
+    } else if name.len() > max_len {
+        Err(InvalidNameError::TooLong)
+    } else {
+        Ok(())
+    }
+}
+
 /// An activity which can occur over one or more [`TimeSlot`](crate::TimeSlot)s.
 ///
 /// *See the [`crate`] documentation for more information*.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// # Serialisation
+///
+/// An `Activity` is serialised as an object whose `type` is the camel case
+/// name of its variant, alongside the fields of the variant (the
+/// [`Location`] of a [`Lesson`](Self::Lesson) is serialised as its room
+/// identifier and the description of a
+/// [`Miscellaneous`](Self::Miscellaneous) activity as its `description`):
+///
+/// ```json
+/// {"type":"lesson","subject":"Maths","class":"Jones","location":"P212"}
+/// {"type":"registration"}
+/// {"type":"miscellaneous","description":"Driving lesson"}
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(from = "ActivityRepr", into = "ActivityRepr")
+)]
 pub enum Activity {
     /// A regular recurring activity that is not known.
     ///
@@ -106,6 +244,7 @@ pub enum Activity {
     /// [default value]: Default
     /// [`None`]: Self::None
     /// [`Miscellaneous`]: Self::Miscellaneous
+    #[default]
     Unknown,
 
     /// A lesson.
@@ -182,35 +321,813 @@ pub enum Activity {
     Miscellaneous(String),
 }
 
-impl Default for Activity {
-    fn default() -> Self {
-        Self::Unknown
+/// The serialised representation of an [`Activity`].
+///
+/// *See the [`Activity`] documentation for more information*.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum ActivityRepr {
+    Unknown,
+    Lesson {
+        subject: Subject,
+        class: Class,
+        #[serde(with = "location_identifier")]
+        location: Location,
+    },
+    Registration,
+    Break,
+    SchoolStudy,
+    HomeStudy,
+    None,
+    Miscellaneous {
+        description: String,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<Activity> for ActivityRepr {
+    fn from(activity: Activity) -> Self {
+        match activity {
+            Activity::Unknown => Self::Unknown,
+            Activity::Lesson {
+                subject,
+                class,
+                location,
+            } => Self::Lesson {
+                subject,
+                class,
+                location,
+            },
+            Activity::Registration => Self::Registration,
+            Activity::Break => Self::Break,
+            Activity::SchoolStudy => Self::SchoolStudy,
+            Activity::HomeStudy => Self::HomeStudy,
+            Activity::None => Self::None,
+            Activity::Miscellaneous(description) => Self::Miscellaneous { description },
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ActivityRepr> for Activity {
+    fn from(repr: ActivityRepr) -> Self {
+        match repr {
+            ActivityRepr::Unknown => Self::Unknown,
+            ActivityRepr::Lesson {
+                subject,
+                class,
+                location,
+            } => Self::Lesson {
+                subject,
+                class,
+                location,
+            },
+            ActivityRepr::Registration => Self::Registration,
+            ActivityRepr::Break => Self::Break,
+            ActivityRepr::SchoolStudy => Self::SchoolStudy,
+            ActivityRepr::HomeStudy => Self::HomeStudy,
+            ActivityRepr::None => Self::None,
+            ActivityRepr::Miscellaneous { description } => Self::Miscellaneous(description),
+        }
+    }
+}
+
+/// (De)serialises a [`Location`] as its room identifier (see its [`Display`]
+/// and [`FromStr`](core::str::FromStr) implementations).
+#[cfg(feature = "serde")]
+mod location_identifier {
+    use crate::Location;
+    use alloc::string::String;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(location: &Location, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(location)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Location, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// The kind of an [`Activity`] (i.e., its variant without any of its data).
+///
+/// *See [`Activity::kind`] for more information*.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ActivityKind {
+    /// An [`Activity::Unknown`].
+    Unknown,
+
+    /// An [`Activity::Lesson`].
+    Lesson,
+
+    /// An [`Activity::Registration`].
+    Registration,
+
+    /// An [`Activity::Break`].
+    Break,
+
+    /// An [`Activity::SchoolStudy`].
+    SchoolStudy,
+
+    /// An [`Activity::HomeStudy`].
+    HomeStudy,
+
+    /// An [`Activity::None`].
+    None,
+
+    /// An [`Activity::Miscellaneous`].
+    Miscellaneous,
+}
+
+impl Activity {
+    /// Creates a new [`Lesson`](Self::Lesson).
+    pub fn lesson(subject: Subject, class: Class, location: Location) -> Self {
+        Self::Lesson {
+            subject,
+            class,
+            location,
+        }
+    }
+
+    /// Creates a new [`Miscellaneous`](Self::Miscellaneous) activity.
+    ///
+    /// # Returns
+    ///
+    /// [`None`] if the `description` is not an ASCII string with a length in
+    /// the range `1..=64` (the same constraints as a [`Subject`] and a
+    /// [`Class`], with a larger maximum length).
+    ///
+    /// # Remarks
+    ///
+    /// The [`Miscellaneous`](Self::Miscellaneous) variant can still be
+    /// constructed directly for descriptions which do not meet these
+    /// constraints.
+    pub fn miscellaneous(description: String) -> Option<Self> {
+        validate_name(&description, 64).ok()?;
+
+        Some(Self::Miscellaneous(description))
+    }
+
+    /// Creates a new [`Lesson`](Self::Lesson) from the name of its
+    /// [`Subject`], the reference of its [`Class`] and the room identifier of
+    /// its [`Location`].
+    ///
+    /// # Errors
+    ///
+    /// A [`LessonError`] is returned if any of the components is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::Activity;
+    /// let lesson = Activity::try_lesson("Maths", "Jones", "P212").unwrap();
+    ///
+    /// assert_eq!(lesson.to_string(), "Maths Jones P212");
+    /// ```
+    pub fn try_lesson(subject: &str, class: &str, location: &str) -> Result<Self, LessonError> {
+        Ok(Self::lesson(
+            Subject::try_from(subject.to_string()).map_err(LessonError::Subject)?,
+            Class::try_from(class.to_string()).map_err(LessonError::Class)?,
+            location.parse().map_err(LessonError::Location)?,
+        ))
+    }
+
+    /// Retrieves the [`Subject`] of the `Activity`, or [`None`] if the
+    /// `Activity` is not a [`Lesson`](Self::Lesson).
+    pub fn subject(&self) -> Option<&Subject> {
+        match self {
+            Self::Lesson { subject, .. } => Some(subject),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the [`Class`] of the `Activity`, or [`None`] if the
+    /// `Activity` is not a [`Lesson`](Self::Lesson).
+    pub fn class(&self) -> Option<&Class> {
+        match self {
+            Self::Lesson { class, .. } => Some(class),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the [`Location`] of the `Activity`, or [`None`] if the
+    /// `Activity` is not a [`Lesson`](Self::Lesson).
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Self::Lesson { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the [`ActivityKind`] of the `Activity`.
+    ///
+    /// # Remarks
+    ///
+    /// This is intended for consumers who need to distinguish between the
+    /// variants of an `Activity` (e.g., to colour them), but not their data.
+    pub fn kind(&self) -> ActivityKind {
+        match self {
+            Self::Unknown => ActivityKind::Unknown,
+            Self::Lesson { .. } => ActivityKind::Lesson,
+            Self::Registration => ActivityKind::Registration,
+            Self::Break => ActivityKind::Break,
+            Self::SchoolStudy => ActivityKind::SchoolStudy,
+            Self::HomeStudy => ActivityKind::HomeStudy,
+            Self::None => ActivityKind::None,
+            Self::Miscellaneous(_) => ActivityKind::Miscellaneous,
+        }
+    }
+
+    /// Retrieves a short label for the `Activity`, for use where its
+    /// [`Display`] form is too wide (e.g., a compact timetable grid).
+    ///
+    /// # Returns
+    ///
+    /// * The name of the subject for a [`Lesson`](Self::Lesson).
+    /// * The first 16 characters of the description for a
+    ///   [`Miscellaneous`](Self::Miscellaneous) activity (the maximum length
+    ///   of a subject's name, such that no label is longer than 16
+    ///   characters).
+    /// * A short token for any other activity (e.g., `Reg` for
+    ///   [`Registration`](Self::Registration)).
+    pub fn short_label(&self) -> String {
+        use Activity::*;
+
+        match self {
+            Unknown => "?".to_string(),
+            Lesson { subject, .. } => subject.name().to_string(),
+            Registration => "Reg".to_string(),
+            Break => "Break".to_string(),
+            SchoolStudy => "Study".to_string(),
+            HomeStudy => "Home".to_string(),
+            None => "N/A".to_string(),
+            Miscellaneous(description) => description.chars().take(16).collect(),
+        }
+    }
+
+    /// Determines whether two activities are the same kind of activity.
+    ///
+    /// This is equivalent to `==`, except [`Miscellaneous`] activities are
+    /// compared case-insensitively (e.g., `Driving lesson` and
+    /// `driving lesson` are the same kind of activity).
+    ///
+    /// [`Miscellaneous`]: Self::Miscellaneous
+    pub fn same_kind(&self, other: &Activity) -> bool {
+        match (self, other) {
+            (Self::Miscellaneous(description), Self::Miscellaneous(other)) => description
+                .chars()
+                .flat_map(char::to_lowercase)
+                .eq(other.chars().flat_map(char::to_lowercase)),
+            _ => self == other,
+        }
     }
 }
 
 impl Display for Activity {
+    // `pad` is used such that the formatter's width, fill and alignment are
+    // honoured (e.g., `{:^10}`)
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         use Activity::*;
 
         match self {
-            Unknown => f.write_str("Unknown"),
+            Unknown => f.pad("Unknown"),
             Lesson {
                 subject,
                 class,
                 location,
             } => {
-                subject.fmt(f)?;
-                f.write_char(' ')?;
-                class.fmt(f)?;
-                f.write_char(' ')?;
-                location.fmt(f)
+                // The components must be written to a buffer first, as `pad`
+                // only accepts a single string
+                let mut buffer = String::new();
+                write!(buffer, "{subject} {class} {location}")?;
+
+                f.pad(&buffer)
+            }
+            Registration => f.pad("Registration"),
+            Break => f.pad("Break"),
+            SchoolStudy => f.pad("Independent Study"),
+            HomeStudy => f.pad("Home Study"),
+            None => f.pad("N/A"),
+            Miscellaneous(description) => f.pad(description),
+        }
+    }
+}
+
+/// An [`Activity`] which spans one or more contiguous [`TimeSlot`]s (e.g., a
+/// double lesson).
+///
+/// *See the [`crate`] documentation for more information*.
+///
+/// # Remarks
+///
+/// An `ActivitySpan` must not span several broken-up periods (i.e., the
+/// periods of a span of more than one period must form a [`PeriodRange`], so
+/// cannot include [`Period::Tutor`], [`Period::Break`] or [`Period::Lunch`]),
+/// nor can it extend past the end of the day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivitySpan {
+    activity: Activity,
+    start: TimeSlot,
+    periods: RangedU8<1, 3>,
+}
+
+impl ActivitySpan {
+    /// Creates a new `ActivitySpan` which starts at the `start` timeslot and
+    /// spans `periods` periods.
+    ///
+    /// # Errors
+    ///
+    /// * [`ActivitySpanError::CrossesDay`] if the span extends past the end of
+    ///   the day.
+    /// * [`ActivitySpanError::CrossesRecess`] if the span includes a period
+    ///   which is not a teaching period ([`Period::Tutor`], [`Period::Break`]
+    ///   or [`Period::Lunch`]) alongside other periods.
+    pub fn new(
+        activity: Activity,
+        start: TimeSlot,
+        periods: RangedU8<1, 3>,
+    ) -> Result<Self, ActivitySpanError> {
+        let first = usize::from(start.period);
+        let count = periods.get() as usize;
+
+        let end =
+            Period::from_index(first + count - 1).map_err(|_| ActivitySpanError::CrossesDay)?;
+
+        // A single-period span cannot be broken up (this allows, for example,
+        // a break activity to occupy the break period)
+        if count > 1 && PeriodRange::new(start.period, end).is_none() {
+            // The range can only be invalid if it contains a period which is
+            // not a teaching period
+            let recess = Period::iter()
+                .skip(first)
+                .find(|period| !period.is_teaching())
+                .unwrap();
+
+            return Err(ActivitySpanError::CrossesRecess(recess));
+        }
+
+        Ok(Self {
+            activity,
+            start,
+            periods,
+        })
+    }
+
+    /// Retrieves the [`Activity`] of the `ActivitySpan`.
+    pub fn activity(&self) -> &Activity {
+        &self.activity
+    }
+
+    /// Retrieves the first [`TimeSlot`] of the `ActivitySpan`.
+    pub fn start(&self) -> TimeSlot {
+        self.start
+    }
+
+    /// Retrieves the number of periods the `ActivitySpan` spans.
+    pub fn periods(&self) -> RangedU8<1, 3> {
+        self.periods
+    }
+
+    /// Retrieves an iterator over every [`TimeSlot`] the `ActivitySpan` spans
+    /// in chronological order.
+    pub fn slots(&self) -> impl Iterator<Item = TimeSlot> {
+        // The span was validated on creation, so it will never extend past
+        // the end of the day (and, therefore, will never need to wrap)
+        iter::successors(Some(self.start), |slot| slot.checked_next())
+            .take(self.periods.get() as usize)
+    }
+}
+
+/// Retrieves the indexes of every pair of `spans` which span at least one of
+/// the same [`TimeSlot`]s (in ascending order, where the first index of each
+/// pair is smaller than the second).
+///
+/// # Examples
+///
+/// ```
+/// # use timetableau::{detect_overlaps, timeslot, Activity, ActivitySpan, RangedU8};
+/// let span = |start, periods| {
+///     ActivitySpan::new(Activity::SchoolStudy, start, RangedU8::new(periods).unwrap()).unwrap()
+/// };
+///
+/// // The double lesson overlaps the second period, but not the third
+/// let spans = [
+///     span(timeslot!(W1MP1), 2),
+///     span(timeslot!(W1MP2), 1),
+///     span(timeslot!(W1MP3), 2),
+/// ];
+///
+/// assert_eq!(detect_overlaps(&spans), [(0, 1)]);
+/// ```
+pub fn detect_overlaps(spans: &[ActivitySpan]) -> Vec<(usize, usize)> {
+    // As each span is contiguous, it covers a range of timeslot indexes
+    let range = |span: &ActivitySpan| {
+        let start = span.start.index();
+
+        start..start + span.periods.get() as usize
+    };
+
+    let mut overlaps = Vec::new();
+
+    for (index, span) in spans.iter().enumerate() {
+        let span = range(span);
+
+        for (other_index, other) in spans.iter().enumerate().skip(index + 1) {
+            let other = range(other);
+
+            if span.start < other.end && other.start < span.end {
+                overlaps.push((index, other_index));
             }
-            Registration => f.write_str("Registration"),
-            Break => f.write_str("Break"),
-            SchoolStudy => f.write_str("Independent Study"),
-            HomeStudy => f.write_str("Home Study"),
-            None => f.write_str("N/A"),
-            Miscellaneous(description) => description.fmt(f),
         }
     }
+
+    overlaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timeslot;
+    #[cfg(feature = "serde")]
+    use crate::{HighfieldBlock, HighfieldFloor, HighfieldRoom};
+
+    #[test]
+    fn activity_span_valid() {
+        let span = ActivitySpan::new(
+            Activity::SchoolStudy,
+            timeslot!(W1MP1),
+            RangedU8::new(2).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            span.slots().collect::<Vec<_>>(),
+            [timeslot!(W1MP1), timeslot!(W1MP2)]
+        );
+    }
+
+    #[test]
+    fn activity_span_invalid() {
+        let across_break = ActivitySpan::new(
+            Activity::SchoolStudy,
+            timeslot!(W1MP2),
+            RangedU8::new(2).unwrap(),
+        );
+        let across_day = ActivitySpan::new(
+            Activity::SchoolStudy,
+            timeslot!(W1MP5),
+            RangedU8::new(2).unwrap(),
+        );
+
+        assert_eq!(
+            across_break,
+            Err(ActivitySpanError::CrossesRecess(Period::Break))
+        );
+        assert_eq!(
+            across_break.unwrap_err().to_string(),
+            "activity is broken up by the BREAK period"
+        );
+        assert_eq!(across_day, Err(ActivitySpanError::CrossesDay));
+
+        // Tutor time cannot be part of a longer span
+        assert_eq!(
+            ActivitySpan::new(
+                Activity::Registration,
+                timeslot!(W1MPT),
+                RangedU8::new(2).unwrap()
+            ),
+            Err(ActivitySpanError::CrossesRecess(Period::Tutor))
+        );
+
+        // A recess can be spanned on its own
+        assert!(
+            ActivitySpan::new(Activity::Break, timeslot!(W1MPL), RangedU8::new(1).unwrap()).is_ok()
+        );
+    }
+
+    #[test]
+    fn activity_span_detect_overlaps() {
+        let span = |start, periods| {
+            ActivitySpan::new(
+                Activity::SchoolStudy,
+                start,
+                RangedU8::new(periods).unwrap(),
+            )
+            .unwrap()
+        };
+
+        let spans = [
+            // A double and a single period which share `W1MP2`
+            span(timeslot!(W1MP1), 2),
+            span(timeslot!(W1MP2), 1),
+            // Two disjoint doubles (the same periods in different weeks)
+            span(timeslot!(W1MP3), 2),
+            span(timeslot!(W2MP3), 2),
+        ];
+
+        assert_eq!(detect_overlaps(&spans), [(0, 1)]);
+
+        // A single period within a double
+        assert_eq!(
+            detect_overlaps(&[span(timeslot!(W1RP4), 1), span(timeslot!(W1RP3), 2)]),
+            [(0, 1)]
+        );
+
+        // Adjacent spans do not overlap
+        assert!(
+            detect_overlaps(&[span(timeslot!(W1MP1), 1), span(timeslot!(W1MP2), 1)]).is_empty()
+        );
+        assert!(detect_overlaps(&[]).is_empty());
+    }
+
+    #[test]
+    fn activity_try_lesson() {
+        use crate::{HighfieldBlock, HighfieldRoom};
+
+        let room = HighfieldRoom::classroom(HighfieldBlock::Parker, 2, 12).unwrap();
+
+        assert_eq!(
+            Activity::try_lesson("Maths", "Jones", "P212"),
+            Ok(Activity::lesson(
+                Subject::new("Maths".to_string()).unwrap(),
+                Class::new("Jones".to_string()).unwrap(),
+                Location::Highfield(room),
+            ))
+        );
+        assert_eq!(
+            Activity::try_lesson("Further Mathematics", "Jones", "P212"),
+            Err(LessonError::Subject(InvalidNameError::TooLong))
+        );
+        assert_eq!(
+            Activity::try_lesson("Maths", "", "P212"),
+            Err(LessonError::Class(InvalidNameError::Empty))
+        );
+        assert!(matches!(
+            Activity::try_lesson("Maths", "Jones", "X212"),
+            Err(LessonError::Location(_))
+        ));
+    }
+
+    #[test]
+    fn activity_miscellaneous() {
+        assert_eq!(
+            Activity::miscellaneous("Driving lesson".to_string()),
+            Some(Activity::Miscellaneous("Driving lesson".to_string()))
+        );
+        assert!(Activity::miscellaneous("a".repeat(64)).is_some());
+
+        assert_eq!(Activity::miscellaneous(String::new()), None);
+        assert_eq!(Activity::miscellaneous("a".repeat(65)), None);
+        assert_eq!(Activity::miscellaneous("Café".to_string()), None);
+    }
+
+    #[test]
+    fn activity_subject_class() {
+        let lesson = Activity::try_lesson("Maths", "Jones", "P212").unwrap();
+
+        assert_eq!(lesson.subject().map(Subject::name), Some("Maths"));
+        assert_eq!(lesson.class().map(Class::reference), Some("Jones"));
+
+        assert_eq!(Activity::Registration.subject(), None);
+        assert_eq!(Activity::Registration.class(), None);
+    }
+
+    #[test]
+    fn activity_location() {
+        let lesson = Activity::try_lesson("Maths", "Jones", "P212").unwrap();
+
+        assert_eq!(lesson.location(), Some(&"P212".parse().unwrap()));
+        assert_eq!(Activity::Break.location(), None);
+    }
+
+    #[test]
+    fn activity_display_padding() {
+        assert_eq!(format!("{:^9}", Activity::Break), "  Break  ");
+        assert_eq!(format!("{:<6}|", Activity::None), "N/A   |");
+        assert_eq!(
+            format!(
+                "{:>20}",
+                Activity::try_lesson("Maths", "Jones", "P212").unwrap()
+            ),
+            "    Maths Jones P212"
+        );
+        assert_eq!(
+            format!("{:-^8}", Activity::Miscellaneous("Trip".to_string())),
+            "--Trip--"
+        );
+    }
+
+    #[test]
+    fn activity_short_label() {
+        use crate::FearnhillRoom;
+
+        let lesson = Activity::Lesson {
+            subject: Subject::new("Maths".to_string()).unwrap(),
+            class: Class::new("Smith".to_string()).unwrap(),
+            location: Location::Fearnhill(FearnhillRoom::Gym),
+        };
+
+        assert_eq!(lesson.short_label(), "Maths");
+        assert_eq!(Activity::Registration.short_label(), "Reg");
+        assert_eq!(Activity::Break.short_label(), "Break");
+        assert_eq!(Activity::SchoolStudy.short_label(), "Study");
+        assert_eq!(Activity::HomeStudy.short_label(), "Home");
+
+        // Long descriptions are truncated
+        assert_eq!(
+            Activity::Miscellaneous("Driving lesson".to_string()).short_label(),
+            "Driving lesson"
+        );
+        assert_eq!(
+            Activity::Miscellaneous("Duke of Edinburgh Award".to_string()).short_label(),
+            "Duke of Edinburg"
+        );
+    }
+
+    #[test]
+    fn activity_kind() {
+        let cases = [
+            (Activity::Unknown, ActivityKind::Unknown),
+            (
+                Activity::try_lesson("Maths", "Jones", "P212").unwrap(),
+                ActivityKind::Lesson,
+            ),
+            (Activity::Registration, ActivityKind::Registration),
+            (Activity::Break, ActivityKind::Break),
+            (Activity::SchoolStudy, ActivityKind::SchoolStudy),
+            (Activity::HomeStudy, ActivityKind::HomeStudy),
+            (Activity::None, ActivityKind::None),
+            (
+                Activity::Miscellaneous("Driving lesson".to_string()),
+                ActivityKind::Miscellaneous,
+            ),
+        ];
+
+        for (activity, kind) in cases {
+            assert_eq!(activity.kind(), kind);
+        }
+    }
+
+    #[test]
+    fn activity_same_kind() {
+        let lower = Activity::Miscellaneous("driving lesson".to_string());
+        let mixed = Activity::Miscellaneous("Driving Lesson".to_string());
+
+        assert!(lower.same_kind(&mixed));
+        assert_ne!(lower, mixed);
+
+        assert!(!lower.same_kind(&Activity::Miscellaneous("Driving".to_string())));
+        assert!(!lower.same_kind(&Activity::SchoolStudy));
+        assert!(Activity::Break.same_kind(&Activity::Break));
+    }
+
+    #[test]
+    fn subject_class_accessors() {
+        let subject = Subject::new("Maths".to_string()).unwrap();
+        let class = Class::new("13X/Ma1".to_string()).unwrap();
+
+        assert_eq!(subject.name(), "Maths");
+        assert_eq!(subject.as_ref(), "Maths");
+        assert_eq!(subject.to_string(), subject.name());
+        assert_eq!(subject.len(), 5);
+
+        assert_eq!(class.reference(), "13X/Ma1");
+        assert_eq!(class.as_ref(), "13X/Ma1");
+        assert_eq!(class.to_string(), class.reference());
+        assert!(class.starts_with("13X"));
+    }
+
+    #[test]
+    fn subject_length() {
+        assert!(Subject::new("a".repeat(16)).is_some());
+        assert!(Subject::new("a".repeat(17)).is_none());
+        assert!(Subject::new(String::new()).is_none());
+
+        // 8 characters, but 16 bytes
+        assert!(Subject::new("é".repeat(8)).is_none());
+    }
+
+    #[test]
+    fn subject_trim() {
+        let subject = Subject::new(" Maths ".into()).unwrap();
+
+        assert_eq!(subject.name(), "Maths");
+        assert_eq!(Some(subject), Subject::new("Maths".into()));
+
+        // The length is checked once the whitespace is removed
+        assert!(Subject::new(format!("  {}  ", "a".repeat(16))).is_some());
+        assert_eq!(
+            Subject::try_from("   ".to_string()),
+            Err(InvalidNameError::Empty)
+        );
+
+        // Internal whitespace is preserved
+        assert_eq!(
+            Subject::new("\tFurther  Maths\n".into()).unwrap().name(),
+            "Further  Maths"
+        );
+    }
+
+    #[test]
+    fn subject_class_try_from() {
+        assert_eq!(
+            Subject::try_from(String::new()),
+            Err(InvalidNameError::Empty)
+        );
+        assert_eq!(
+            Subject::try_from("Further Mathematics".to_string()),
+            Err(InvalidNameError::TooLong)
+        );
+        assert_eq!(
+            Subject::try_from("Español".to_string()),
+            Err(InvalidNameError::NonAscii)
+        );
+
+        assert_eq!(Class::try_from(String::new()), Err(InvalidNameError::Empty));
+        assert_eq!(
+            Class::try_from("a".repeat(33)),
+            Err(InvalidNameError::TooLong)
+        );
+        assert_eq!(
+            Class::try_from("Müller".to_string()),
+            Err(InvalidNameError::NonAscii)
+        );
+
+        assert_eq!(
+            Subject::try_from("Maths".to_string()).ok(),
+            Subject::new("Maths".to_string())
+        );
+    }
+
+    #[test]
+    fn class_length() {
+        assert!(Class::new("a".repeat(32)).is_some());
+        assert!(Class::new("a".repeat(33)).is_none());
+        assert!(Class::new(String::new()).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn activity_serde_round_trip() {
+        let lesson = Activity::Lesson {
+            subject: Subject::new("Maths".to_string()).unwrap(),
+            class: Class::new("Smith".to_string()).unwrap(),
+            location: Location::Highfield(HighfieldRoom::Classroom {
+                block: HighfieldBlock::Parker,
+                floor: HighfieldFloor::Level(RangedU8::new(2).unwrap()),
+                discriminator: RangedU8::new(12).unwrap(),
+            }),
+        };
+
+        let json = serde_json::to_string(&lesson).unwrap();
+
+        assert_eq!(serde_json::from_str::<Activity>(&json).unwrap(), lesson);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn activity_serde_shape() {
+        let lesson = Activity::try_lesson("Maths", "Jones", "P212").unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&lesson).unwrap(),
+            r#"{"type":"lesson","subject":"Maths","class":"Jones","location":"P212"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Activity::Registration).unwrap(),
+            r#"{"type":"registration"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Activity::SchoolStudy).unwrap(),
+            r#"{"type":"schoolStudy"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Activity::Miscellaneous("Trip".to_string())).unwrap(),
+            r#"{"type":"miscellaneous","description":"Trip"}"#
+        );
+
+        // Invalid room identifiers are rejected
+        assert!(serde_json::from_str::<Activity>(
+            r#"{"type":"lesson","subject":"Maths","class":"Jones","location":"X212"}"#
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn subject_serde_invalid() {
+        assert!(serde_json::from_str::<Subject>(r#""""#).is_err());
+        assert!(serde_json::from_str::<Subject>(r#""Further Mathematics""#).is_err());
+        assert!(serde_json::from_str::<Class>(r#""Müller""#).is_err());
+    }
 }
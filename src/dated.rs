@@ -0,0 +1,128 @@
+use crate::{ParseTimeSlotError, TimeSlot};
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A [`TimeSlot`] within a specific iteration of the timetable.
+///
+/// *See the [`crate`] documentation for more information*.
+///
+/// # Ordering
+///
+/// Unlike [`TimeSlot`]s, which are iteration independent, `DatedTimeSlot`s
+/// are ordered by their `iteration` first, and then by their `slot` (e.g.,
+/// `I2W1MPT` is greater than `I1W2FP5`).
+// The derived implementations compare the fields in declaration order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DatedTimeSlot {
+    pub iteration: u32,
+    pub slot: TimeSlot,
+}
+
+impl Display for DatedTimeSlot {
+    // Format the DatedTimeSlot using its WDF notation (e.g., `I2W1RP2`)
+    //
+    // See the crate level documentation for more information
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "I{}{}", self.iteration, self.slot)
+    }
+}
+
+impl FromStr for DatedTimeSlot {
+    type Err = ParseTimeSlotError;
+
+    /// Parses a `DatedTimeSlot` from its WDF notation (e.g., `I2W1RP2`).
+    ///
+    /// *See the [`crate`] documentation for more information*.
+    ///
+    /// # Remarks
+    ///
+    /// Like [`TimeSlot`]s, the WDF notation provided **MUST** be uppercase.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ParseTimeSlotError::*;
+
+        let s = match s.chars().next() {
+            Some('I') => &s[1..],
+            Some(found) => {
+                return Err(ExpectedMarker {
+                    expected: 'I',
+                    found,
+                })
+            }
+            None => return Err(UnexpectedEnd),
+        };
+
+        // The iteration is every digit up to the `W` marker of the timeslot
+        let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (iteration, slot) = s.split_at(end);
+
+        Ok(Self {
+            iteration: iteration.parse().map_err(|_| InvalidIteration)?,
+            // The slot is parsed without an iteration prefix, such that a
+            // second prefix (e.g., `I1I2W1MP2`) is rejected
+            slot: TimeSlot::parse_unprefixed(slot, true)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timeslot;
+
+    fn dated(iteration: u32, slot: TimeSlot) -> DatedTimeSlot {
+        DatedTimeSlot { iteration, slot }
+    }
+
+    #[test]
+    fn dated_timeslot_ordering() {
+        assert!(dated(1, timeslot!(W2FP5)) > dated(1, timeslot!(W1MPT)));
+        assert!(dated(2, timeslot!(W1MPT)) > dated(1, timeslot!(W2FP5)));
+        assert!(dated(3, timeslot!(W1MP1)) < dated(3, timeslot!(W1MP2)));
+    }
+
+    #[test]
+    fn dated_timeslot_parse_valid() {
+        assert_eq!("I2W1MP2".parse(), Ok(dated(2, timeslot!(W1MP2))));
+        assert_eq!("I10W2RPL".parse(), Ok(dated(10, timeslot!(W2RPL))));
+        assert_eq!("I2W1DMP2".parse(), Ok(dated(2, timeslot!(W1MP2))));
+    }
+
+    #[test]
+    fn dated_timeslot_parse_invalid() {
+        use ParseTimeSlotError::*;
+
+        assert_eq!("".parse::<DatedTimeSlot>(), Err(UnexpectedEnd));
+        assert_eq!(
+            "W1MP2".parse::<DatedTimeSlot>(),
+            Err(ExpectedMarker {
+                expected: 'I',
+                found: 'W'
+            })
+        );
+        assert_eq!("IW1MP2".parse::<DatedTimeSlot>(), Err(InvalidIteration));
+        assert_eq!(
+            "I99999999999W1MP2".parse::<DatedTimeSlot>(),
+            Err(InvalidIteration)
+        );
+        assert_eq!("I2W3MP2".parse::<DatedTimeSlot>(), Err(InvalidWeek('3')));
+        assert_eq!(
+            "I1I2W1MP2".parse::<DatedTimeSlot>(),
+            Err(ExpectedMarker {
+                expected: 'W',
+                found: 'I'
+            })
+        );
+    }
+
+    #[test]
+    fn dated_timeslot_round_trip() {
+        for slot in TimeSlot::iter() {
+            let dated = dated(7, slot);
+
+            assert_eq!(dated.to_string().parse(), Ok(dated));
+        }
+    }
+}
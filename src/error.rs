@@ -0,0 +1,627 @@
+#[cfg(feature = "csv")]
+use crate::ActiveDay;
+use crate::{Activity, Period, TimeSlot};
+use alloc::string::String;
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDateTime, Weekday};
+use core::error;
+use core::fmt::{self, Display, Formatter};
+
+/// An error which can be returned when parsing a [`TimeSlot`] from its WDF
+/// notation.
+///
+/// *See the [`crate`] documentation for more information*.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseTimeSlotError {
+    /// The string ended before a complete `TimeSlot` could be read.
+    UnexpectedEnd,
+
+    /// The iteration (after the `I` marker) was not a valid number.
+    InvalidIteration,
+
+    /// A marker character (`I` before the iteration, `W` before the week, `P`
+    /// before the period) was expected, but another character was found instead.
+    ExpectedMarker { expected: char, found: char },
+
+    /// The week was neither `1` nor `2`.
+    InvalidWeek(char),
+
+    /// The day letter did not refer to an active day (e.g., `S`, as Saturday
+    /// and Sunday are inactive days).
+    InvalidDay(char),
+
+    /// The period token was not one of `T`, `1`, `2`, `B`, `3`, `4`, `L`, or
+    /// `5`.
+    InvalidPeriod(char),
+
+    /// Additional characters were found after a complete `TimeSlot` was read.
+    TrailingCharacters,
+}
+
+impl Display for ParseTimeSlotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use ParseTimeSlotError::*;
+
+        match self {
+            UnexpectedEnd => f.write_str("unexpected end of timeslot"),
+            InvalidIteration => f.write_str("the iteration is not a valid number"),
+            ExpectedMarker { expected, found } => {
+                write!(f, "expected `{expected}`, found `{found}`")
+            }
+            InvalidWeek(week) => write!(f, "`{week}` is not a valid week"),
+            InvalidDay(day) => write!(f, "`{day}` is not a valid active day"),
+            InvalidPeriod(period) => write!(f, "`{period}` is not a valid period"),
+            TrailingCharacters => f.write_str("unexpected characters after timeslot"),
+        }
+    }
+}
+
+impl error::Error for ParseTimeSlotError {}
+
+/// An error which can be returned when converting a [`TimeSlot`] into a
+/// [`DateTime`](chrono::DateTime).
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeError {
+    /// The date provided as the start of week one was not a Monday.
+    NotMonday(Weekday),
+
+    /// The local date and time does not exist in the timezone provided.
+    NonexistentTime(NaiveDateTime),
+
+    /// The date could not be represented.
+    OutOfRange,
+}
+
+#[cfg(feature = "chrono")]
+impl Display for DateTimeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use DateTimeError::*;
+
+        match self {
+            NotMonday(weekday) => write!(f, "week one must start on a Monday, not a {weekday}"),
+            NonexistentTime(datetime) => write!(f, "`{datetime}` does not exist in the timezone"),
+            OutOfRange => f.write_str("date out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl error::Error for DateTimeError {}
+
+/// An error which is returned when converting an inactive day (i.e., Saturday
+/// or Sunday) into an [`ActiveDay`](crate::ActiveDay).
+///
+/// *See the [`crate`] documentation for more information*.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InactiveDayError(pub Weekday);
+
+#[cfg(feature = "chrono")]
+impl Display for InactiveDayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not an active day", self.0)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl error::Error for InactiveDayError {}
+
+/// An error which can be returned when parsing a room identifier.
+///
+/// *See the [`crate`] documentation for more information*.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseLocationError {
+    /// The room identifier ended before a complete room was read.
+    UnexpectedEnd,
+
+    /// The block was not one of `H`, `P`, or `U`.
+    InvalidBlock(String),
+
+    /// The floor was neither `G` nor a number in the range `1..=9`.
+    InvalidFloor(char),
+
+    /// The section was not one of the sections at the Fearnhill school.
+    InvalidSection(String),
+
+    /// The discriminator was not a number in the range `1..=99` (padded to
+    /// two digits for Highfield classrooms).
+    InvalidDiscriminator(String),
+}
+
+impl Display for ParseLocationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use ParseLocationError::*;
+
+        match self {
+            UnexpectedEnd => f.write_str("unexpected end of room identifier"),
+            InvalidBlock(block) => write!(f, "`{block}` is not a valid block"),
+            InvalidFloor(floor) => write!(f, "`{floor}` is not a valid floor"),
+            InvalidSection(section) => write!(f, "`{section}` is not a valid section"),
+            InvalidDiscriminator(discriminator) => {
+                write!(f, "`{discriminator}` is not a valid discriminator")
+            }
+        }
+    }
+}
+
+impl error::Error for ParseLocationError {}
+
+/// An error which can be returned when creating a [`Subject`](crate::Subject) or a [`Class`](crate::Class).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidNameError {
+    /// The string was empty.
+    Empty,
+
+    /// The string was longer than the maximum length.
+    TooLong,
+
+    /// The string contained non-ASCII characters.
+    NonAscii,
+}
+
+impl Display for InvalidNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "the name is empty",
+            Self::TooLong => "the name is too long",
+            Self::NonAscii => "the name contains non-ASCII characters",
+        })
+    }
+}
+
+impl error::Error for InvalidNameError {}
+
+/// An error which can be returned when creating a [`Lesson`] from strings.
+///
+/// *See [`Activity::try_lesson`] for more information*.
+///
+/// [`Lesson`]: Activity::Lesson
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LessonError {
+    /// The name of the [`Subject`](crate::Subject) was invalid.
+    Subject(InvalidNameError),
+
+    /// The reference of the [`Class`](crate::Class) was invalid.
+    Class(InvalidNameError),
+
+    /// The room identifier of the [`Location`](crate::Location) was invalid.
+    Location(ParseLocationError),
+}
+
+impl Display for LessonError {
+    // Only the context is written, as the underlying error is the source
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Subject(_) => f.write_str("invalid subject"),
+            Self::Class(_) => f.write_str("invalid class"),
+            Self::Location(_) => f.write_str("invalid location"),
+        }
+    }
+}
+
+impl error::Error for LessonError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Subject(error) | Self::Class(error) => Some(error),
+            Self::Location(error) => Some(error),
+        }
+    }
+}
+
+/// An error which can be returned when creating an [`ActivitySpan`](crate::ActivitySpan).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivitySpanError {
+    /// The span extended past the last period of the day.
+    CrossesDay,
+
+    /// The span included a recess or tutor time (and is, therefore, broken
+    /// up).
+    CrossesRecess(Period),
+}
+
+impl Display for ActivitySpanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CrossesDay => f.write_str("activity spans past the end of the day"),
+            Self::CrossesRecess(period) => {
+                write!(f, "activity is broken up by the {} period", period.label())
+            }
+        }
+    }
+}
+
+impl error::Error for ActivitySpanError {}
+
+/// An error which is returned when an [`Activity`] is assigned to a
+/// [`TimeSlot`] which has already been assigned an [`Activity`].
+///
+/// *See [`TimetableBuilder::try_set`](crate::TimetableBuilder::try_set) for more information*.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictError {
+    /// The [`TimeSlot`] which was double-booked.
+    pub slot: TimeSlot,
+
+    /// The [`Activity`] already assigned to the [`TimeSlot`].
+    pub existing: Activity,
+}
+
+impl Display for ConflictError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} has already been assigned {}",
+            self.slot, self.existing
+        )
+    }
+}
+
+impl error::Error for ConflictError {}
+
+/// An error which can be returned when importing a [`Timetable`](crate::Timetable) from a CSV
+/// grid.
+///
+/// *See [`Timetable::from_csv`](crate::Timetable::from_csv) for more information*.
+#[cfg(feature = "csv")]
+#[derive(Debug)]
+pub enum ImportError {
+    /// The CSV could not be read (e.g., a row had the wrong number of cells).
+    Csv(csv::Error),
+
+    /// The header row did not list the active days in chronological order.
+    InvalidHeader,
+
+    /// The first cell of a row was not the start time of a [`Period`].
+    InvalidPeriod { line: u64, value: String },
+
+    /// A cell could not be parsed into an activity (e.g., an incomplete
+    /// lesson, or a miscellaneous activity which is not ASCII).
+    InvalidLesson {
+        line: u64,
+        day: ActiveDay,
+        value: String,
+    },
+}
+
+#[cfg(feature = "csv")]
+impl Display for ImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use ImportError::*;
+
+        match self {
+            Csv(error) => Display::fmt(error, f),
+            InvalidHeader => f.write_str("the header must list the days from Monday to Friday"),
+            InvalidPeriod { line, value } => {
+                write!(f, "line {line}: `{value}` is not the start of a period")
+            }
+            InvalidLesson { line, day, value } => {
+                write!(
+                    f,
+                    "line {line}: `{value}` on {} is not a valid activity",
+                    day.name()
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl error::Error for ImportError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        // The CSV error is displayed transparently, so its source is skipped
+        // to (such that its message is not reported twice)
+        match self {
+            Self::Csv(error) => error.source(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for ImportError {
+    fn from(error: csv::Error) -> Self {
+        Self::Csv(error)
+    }
+}
+
+/// An error which can be returned when parsing a [`Timetable`](crate::Timetable) from its JSON
+/// representation.
+///
+/// *See [`Timetable::from_json`](crate::Timetable::from_json) for more information*.
+#[cfg(feature = "json")]
+#[derive(Debug)]
+pub enum JsonError {
+    /// The JSON was malformed or did not match the schema (including if a
+    /// timeslot was not valid WDF notation).
+    Json(serde_json::Error),
+
+    /// The schema `version` was missing or unsupported.
+    UnsupportedVersion(Option<String>),
+}
+
+#[cfg(feature = "json")]
+impl Display for JsonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use JsonError::*;
+
+        match self {
+            Json(error) => Display::fmt(error, f),
+            UnsupportedVersion(None) => f.write_str("the schema version is missing"),
+            UnsupportedVersion(Some(version)) => write!(
+                f,
+                "unsupported schema version `{version}` (expected `{}`)",
+                crate::Timetable::JSON_VERSION
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl error::Error for JsonError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        // The JSON error is displayed transparently, so its source is skipped
+        // to (such that its message is not reported twice)
+        match self {
+            Self::Json(error) => error.source(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for JsonError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+/// An error which is returned when converting an integer into a ranged
+/// integer (e.g., [`RangedU8`](crate::RangedU8)) whose range does not contain
+/// it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRangeError;
+
+impl Display for OutOfRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("value out of range")
+    }
+}
+
+impl error::Error for OutOfRangeError {}
+
+/// An error which is returned when creating a value from an index (e.g., using
+/// [`Period::from_index`]) which is not less than the number of values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfRangeError {
+    /// The index provided.
+    pub index: usize,
+
+    /// The number of values (i.e., every valid index is less than `len`).
+    pub len: usize,
+}
+
+impl Display for IndexOutOfRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the index is {} but the number of values is {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl error::Error for IndexOutOfRangeError {}
+
+/// Any error which can be returned by the crate.
+///
+/// Each of the crate's error types can be converted into an `Error` (e.g.,
+/// using the `?` operator), such that consumers can use a single error type.
+///
+/// # Remarks
+///
+/// An `Error` is displayed as the underlying error, so its
+/// [`source`](error::Error::source) is the source of the underlying error
+/// (rather than the underlying error itself) -- as such, error reporters
+/// which walk the chain of sources do not report any message twice.
+// non_exhaustive is used as the variants depend on the features enabled
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    ParseTimeSlot(ParseTimeSlotError),
+    #[cfg(feature = "chrono")]
+    DateTime(DateTimeError),
+    #[cfg(feature = "chrono")]
+    InactiveDay(InactiveDayError),
+    ParseLocation(ParseLocationError),
+    InvalidName(InvalidNameError),
+    Lesson(LessonError),
+    ActivitySpan(ActivitySpanError),
+    Conflict(ConflictError),
+    OutOfRange(OutOfRangeError),
+    IndexOutOfRange(IndexOutOfRangeError),
+    #[cfg(feature = "csv")]
+    Import(ImportError),
+    #[cfg(feature = "json")]
+    Json(JsonError),
+}
+
+impl Display for Error {
+    // Forward to the underlying error
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParseTimeSlot(error) => Display::fmt(error, f),
+            #[cfg(feature = "chrono")]
+            Self::DateTime(error) => Display::fmt(error, f),
+            #[cfg(feature = "chrono")]
+            Self::InactiveDay(error) => Display::fmt(error, f),
+            Self::ParseLocation(error) => Display::fmt(error, f),
+            Self::InvalidName(error) => Display::fmt(error, f),
+            Self::Lesson(error) => Display::fmt(error, f),
+            Self::ActivitySpan(error) => Display::fmt(error, f),
+            Self::Conflict(error) => Display::fmt(error, f),
+            Self::OutOfRange(error) => Display::fmt(error, f),
+            Self::IndexOutOfRange(error) => Display::fmt(error, f),
+            #[cfg(feature = "csv")]
+            Self::Import(error) => Display::fmt(error, f),
+            #[cfg(feature = "json")]
+            Self::Json(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        // `Error` is displayed transparently, so the source of the underlying
+        // error is skipped to (such that its message is not reported twice)
+        match self {
+            Self::ParseTimeSlot(error) => error.source(),
+            #[cfg(feature = "chrono")]
+            Self::DateTime(error) => error.source(),
+            #[cfg(feature = "chrono")]
+            Self::InactiveDay(error) => error.source(),
+            Self::ParseLocation(error) => error.source(),
+            Self::InvalidName(error) => error.source(),
+            Self::Lesson(error) => error.source(),
+            Self::ActivitySpan(error) => error.source(),
+            Self::Conflict(error) => error.source(),
+            Self::OutOfRange(error) => error.source(),
+            Self::IndexOutOfRange(error) => error.source(),
+            #[cfg(feature = "csv")]
+            Self::Import(error) => error.source(),
+            #[cfg(feature = "json")]
+            Self::Json(error) => error.source(),
+        }
+    }
+}
+
+macro_rules! impl_from_error {
+    ( $( $( #[$attr: meta] )* $variant: ident($error: ty); )+ ) => {
+        $(
+            $(#[$attr])*
+            impl From<$error> for Error {
+                fn from(error: $error) -> Self {
+                    Self::$variant(error)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_error!(
+    ParseTimeSlot(ParseTimeSlotError);
+    #[cfg(feature = "chrono")]
+    DateTime(DateTimeError);
+    #[cfg(feature = "chrono")]
+    InactiveDay(InactiveDayError);
+    ParseLocation(ParseLocationError);
+    InvalidName(InvalidNameError);
+    Lesson(LessonError);
+    ActivitySpan(ActivitySpanError);
+    Conflict(ConflictError);
+    OutOfRange(OutOfRangeError);
+    IndexOutOfRange(IndexOutOfRangeError);
+    #[cfg(feature = "csv")]
+    Import(ImportError);
+    #[cfg(feature = "json")]
+    Json(JsonError);
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Location, RangedU8};
+    use alloc::boxed::Box;
+
+    #[test]
+    fn error_boxed() {
+        fn parse(timeslot: &str, location: &str) -> Result<(), Box<dyn error::Error>> {
+            timeslot.parse::<TimeSlot>()?;
+            location.parse::<Location>()?;
+
+            Ok(())
+        }
+
+        assert!(parse("W1MP1", "P212").is_ok());
+        assert_eq!(
+            parse("W3MP1", "P212").unwrap_err().to_string(),
+            ParseTimeSlotError::InvalidWeek('3').to_string()
+        );
+        assert!(parse("W1MP1", "X212")
+            .unwrap_err()
+            .downcast_ref::<ParseLocationError>()
+            .is_some());
+    }
+
+    #[test]
+    fn error_from() {
+        fn parse(timeslot: &str, discriminator: u8) -> Result<(), Error> {
+            timeslot.parse::<TimeSlot>()?;
+            RangedU8::<1, 99>::try_from(discriminator)?;
+
+            Ok(())
+        }
+
+        assert!(parse("W1MP1", 12).is_ok());
+        assert!(matches!(
+            parse("W1MP", 12),
+            Err(Error::ParseTimeSlot(ParseTimeSlotError::UnexpectedEnd))
+        ));
+        assert!(matches!(
+            parse("W1MP1", 100),
+            Err(Error::OutOfRange(OutOfRangeError))
+        ));
+
+        let error: Box<dyn error::Error> = Box::new(Error::from(OutOfRangeError));
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn error_source_chain() {
+        // Retrieves the message of every error in the chain of sources
+        fn chain(error: Error) -> Vec<String> {
+            let error: Box<dyn error::Error> = Box::new(error);
+            let mut messages = Vec::new();
+            let mut current: Option<&dyn error::Error> = Some(&*error);
+
+            while let Some(error) = current {
+                messages.push(error.to_string());
+                current = error.source();
+            }
+
+            messages
+        }
+
+        assert_eq!(
+            chain(Error::Lesson(LessonError::Subject(InvalidNameError::Empty))),
+            [
+                "invalid subject".to_string(),
+                InvalidNameError::Empty.to_string()
+            ]
+        );
+
+        let errors = [
+            Error::Lesson(LessonError::Class(InvalidNameError::TooLong)),
+            Error::Lesson(LessonError::Location(
+                "X212".parse::<Location>().unwrap_err(),
+            )),
+            Error::ParseTimeSlot(ParseTimeSlotError::UnexpectedEnd),
+            Error::OutOfRange(OutOfRangeError),
+        ];
+
+        #[cfg(feature = "json")]
+        let errors = errors
+            .into_iter()
+            .chain([Error::Json(crate::Timetable::from_json("{").unwrap_err())]);
+
+        for error in errors {
+            let messages = chain(error);
+
+            // No message is repeated (either in full, or within another)
+            for (index, message) in messages.iter().enumerate() {
+                for other in &messages[index + 1..] {
+                    assert!(!message.contains(other.as_str()), "{messages:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use chrono::NaiveDate;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A date on which the timetable is not followed (e.g., an inset day).
+///
+/// *See the [`crate`] documentation for more information*.
+///
+/// # Remarks
+///
+/// An `Exception` only describes a deviation from the timetable -- it does
+/// not describe what happens instead (i.e., a consumer who needs to know what
+/// a student does on a [`ExceptionKind::Modified`] day must record that
+/// themselves).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Exception {
+    /// The date on which the timetable is not followed.
+    pub date: NaiveDate,
+
+    /// The way in which the timetable is deviated from.
+    pub kind: ExceptionKind,
+}
+
+impl Exception {
+    /// Creates a new `Exception` for the `date`.
+    pub fn new(date: NaiveDate, kind: ExceptionKind) -> Self {
+        Self { date, kind }
+    }
+}
+
+/// The way in which the timetable is deviated from on the date of an
+/// [`Exception`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExceptionKind {
+    /// The school is closed (e.g., an inset day or a bank holiday).
+    NoSchool,
+
+    /// The school is open, but the timetable is not followed (e.g., a
+    /// sports day or an exam).
+    Modified,
+}
@@ -169,6 +169,11 @@
 //! It should be noted that the end time is **not** included in the period
 //! (e.g., `09:50` belongs to the second time slot, not the first).
 //!
+//! Fearnhill follows exactly the same timetable structure (the same weeks,
+//! active days and periods) such that consortium students can attend lessons
+//! at both schools -- as a result, a single [`TimeSlot`] type is used for
+//! lessons at either school.
+//!
 //! ### Example Timetable
 //!
 //! <div align="center">
@@ -206,16 +211,15 @@
 //! ### WDF Notation
 //!
 //! WDF notation is a shorthand method of referring to an individual
-//! timeslot within Highfield's timetable -- it takes the form of `W#D#P#`:
+//! timeslot within Highfield's timetable -- it takes the form of `W#DP#`:
 //!
 //! * The first `#` refers to the week (this can be either `1` or `2`).
 //!
-//! * The second `#` refers to the day (this is the first letter of the
-//!   weekday of the period -- `R` is used to represent Thursday, Saturday
-//!   and Sunday are inactive days and cannot be referred to using this
-//!   notation).
+//! * The `D` refers to the day (this is the first letter of the weekday of
+//!   the period -- `R` is used to represent Thursday, Saturday and Sunday
+//!   are inactive days and cannot be referred to using this notation).
 //!
-//! * The third and final `#` refers to the period -- this can be any one of
+//! * The second and final `#` refers to the period -- this can be any one of
 //!   the following:
 //!
 //!   - A number between `1`, for first period, and `5`, for the fifth period.
@@ -223,15 +227,24 @@
 //!   - A `B` for break time (the recess which starts at `10:50` and ends at `11:10`.
 //!   - An `L` for lunch time (the recess which starts at `13:10` and ends at `13:55`.
 //!
-//! For example, `W1DMP2` refers to the second timeslot on a week one Monday,
-//! `W2DRP5` refers to the last timeslot of a week two Thursday, and `W1DSP1`
+//! For example, `W1MP2` refers to the second timeslot on a week one Monday,
+//! `W2RP5` refers to the last timeslot of a week two Thursday, and `W1SP1`
 //! is invalid (as no *active* day starts with that letter).
 //!
+//! This is the canonical form of the notation, which is used by the
+//! [`timeslot!`] macro, [`TimeSlot::parse_wdf`] and the [`Display`]
+//! implementation of [`TimeSlot`] -- older documents may use a legacy form in
+//! which the day is preceded by a literal `D` (e.g., `W1DMP2`), which is
+//! accepted by the [`FromStr`] implementation of [`TimeSlot`].
+//!
 //! In some cases, it may be necessary to refer to the iteration of a
 //! timetable -- to refer to a specific iteration using this notation, prefix
-//! the notation with `I#`, where `#` is the iteration (e.g., `I2W1DMP2` refers
+//! the notation with `I#`, where `#` is the iteration (e.g., `I2W1MP2` refers
 //! to the second period on a Monday on the second iteration of week one).
 //!
+//! [`Display`]: core::fmt::Display
+//! [`FromStr`]: core::str::FromStr
+//!
 //! ## Activities
 //!
 //! An activity is a scheduled unit for performing a specific function -- for
@@ -277,12 +290,33 @@
 //! [the Highfield school]: https://highfield.herts.sch.uk/
 //! [the Fearnhill school]: https://fearnhill.herts.sch.uk/
 
-pub use activity::{Activity, Class, Subject};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub use activity::{detect_overlaps, Activity, ActivityKind, ActivitySpan, Class, Subject};
+pub use dated::DatedTimeSlot;
+#[cfg(feature = "csv")]
+pub use error::ImportError;
+#[cfg(feature = "json")]
+pub use error::JsonError;
+pub use error::{
+    ActivitySpanError, ConflictError, Error, IndexOutOfRangeError, InvalidNameError, LessonError,
+    OutOfRangeError, ParseLocationError, ParseTimeSlotError,
+};
+#[cfg(feature = "chrono")]
+pub use error::{DateTimeError, InactiveDayError};
+#[cfg(feature = "chrono")]
+pub use exception::{Exception, ExceptionKind};
 pub use location::{
-    FearnhillRoom, FearnhillSection, HighfieldBlock, HighfieldFloor, HighfieldRoom, Location,
+    FearnhillRoom, FearnhillSection, HighfieldBlock, HighfieldFloor, HighfieldRoom,
+    HighfieldRoomBuilder, Location,
 };
 pub use ranged::*;
-pub use timeslot::{ActiveDay, Period, TimeSlot, Week};
+#[cfg(feature = "chrono")]
+pub use schedule::Schedule;
+pub use timeslot::{ActiveDay, Period, PeriodRange, TimeSlot, Week};
+pub use timetable::{RuleViolation, Timetable, TimetableBuilder, TimetableIter};
 
 mod ranged;
 
@@ -292,4 +326,17 @@ mod location;
 
 mod timeslot;
 
+mod dated;
+
+#[cfg(feature = "chrono")]
+mod exception;
+
 mod activity;
+
+mod timetable;
+
+#[cfg(feature = "chrono")]
+mod schedule;
+
+/// This module contains every error which can be returned by the crate.
+mod error;
@@ -1,10 +1,21 @@
-use crate::RangedU8;
-use std::fmt::{self, Debug, Display, Formatter, Write};
+use crate::{ParseLocationError, RangedU8};
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter, Write};
+use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A block at the Highfield school.
 ///
 /// *See the [`crate`] documentation for more information*
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// # Ordering
+///
+/// Blocks are ordered alphabetically (i.e., by their identifiers).
+// The derived ordering is used as the variants are declared alphabetically
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum HighfieldBlock {
     Howard,
     Parker,
@@ -24,8 +35,32 @@ impl Display for HighfieldBlock {
     }
 }
 
+impl FromStr for HighfieldBlock {
+    type Err = ParseLocationError;
+
+    /// Parses a `HighfieldBlock` from its identifier (the inverse of its
+    /// [`Display`] implementation).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use HighfieldBlock::*;
+
+        match s {
+            "H" => Ok(Howard),
+            "P" => Ok(Parker),
+            "U" => Ok(Unwin),
+            block => Err(ParseLocationError::InvalidBlock(block.to_string())),
+        }
+    }
+}
+
 /// A floor of a [`HighfieldBlock`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// # Ordering
+///
+/// Floors are ordered from the lowest to the highest (i.e., the ground floor
+/// is ordered before every level).
+// The derived ordering is used as `Ground` is declared before `Level`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum HighfieldFloor {
     /// The ground floor of a block.
     Ground,
@@ -40,6 +75,46 @@ pub enum HighfieldFloor {
     Level(RangedU8<1, 9>),
 }
 
+impl HighfieldFloor {
+    /// Creates a `HighfieldFloor` from its `level`, where `0` refers to the
+    /// ground floor.
+    ///
+    /// # Returns
+    ///
+    /// [`None`] if the `level` is outside the range `0..=9`.
+    pub fn from_level(level: u8) -> Option<Self> {
+        match level {
+            0 => Some(Self::Ground),
+            level => RangedU8::new(level).map(Self::Level),
+        }
+    }
+
+    /// Creates a `HighfieldFloor` from its identifier (the inverse of its
+    /// [`Display`] implementation) -- i.e., `G` for the ground floor, or the
+    /// digit of its level.
+    ///
+    /// # Returns
+    ///
+    /// [`None`] if the `identifier` is not `G` or a digit in the range
+    /// `1..=9`.
+    pub fn from_char(identifier: char) -> Option<Self> {
+        match identifier {
+            'G' => Some(Self::Ground),
+            level @ '1'..='9' => Self::from_level(level as u8 - b'0'),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the level of the `HighfieldFloor`, where `0` refers to the
+    /// ground floor.
+    pub fn level(self) -> u8 {
+        match self {
+            Self::Ground => 0,
+            Self::Level(level) => level.get(),
+        }
+    }
+}
+
 impl Display for HighfieldFloor {
     // Format the HighfieldFloor
     // Use 'G' for the ground floor and the floor number for others
@@ -52,7 +127,15 @@ impl Display for HighfieldFloor {
 }
 
 /// A room at the Highfield school.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// # Ordering
+///
+/// Named rooms (e.g., [`HighfieldRoom::Hall`]) are ordered before
+/// classrooms, in the order in which they are declared. Classrooms are
+/// ordered by their block, then their floor, then their discriminator (e.g.,
+/// `HG01 < H101 < P212`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 // non_exhaustive is used for two reasons:
 //  1. An exhaustive list of all of Highfield's rooms has not yet been
 //     obtained
@@ -84,6 +167,168 @@ pub enum HighfieldRoom {
     },
 }
 
+impl HighfieldRoom {
+    /// Creates a new [`HighfieldRoom::Classroom`].
+    ///
+    /// A `floor` of `0` refers to the ground floor, and any other `floor`
+    /// refers to that level of the `block`.
+    ///
+    /// # Returns
+    ///
+    /// [`None`] if the `floor` is outside the range `0..=9` or the
+    /// `discriminator` is outside the range `1..=99`.
+    pub fn classroom(block: HighfieldBlock, floor: u8, discriminator: u8) -> Option<Self> {
+        Some(Self::Classroom {
+            block,
+            floor: HighfieldFloor::from_level(floor)?,
+            discriminator: RangedU8::new(discriminator)?,
+        })
+    }
+
+    /// Creates a [`HighfieldRoom::Classroom`] from its room identifier (e.g.,
+    /// `P212`), panicking if the identifier is invalid.
+    ///
+    /// This is an implementation detail of the [`crate::room!`] macro -- as
+    /// it is evaluated in a constant context, an invalid identifier causes a
+    /// compilation error rather than a runtime panic.
+    #[doc(hidden)]
+    pub const fn __classroom(identifier: &str) -> Self {
+        let identifier = identifier.as_bytes();
+
+        if identifier.len() != 4 {
+            panic!("a classroom identifier must be four characters long");
+        }
+
+        let block = match identifier[0] {
+            b'H' => HighfieldBlock::Howard,
+            b'P' => HighfieldBlock::Parker,
+            b'U' => HighfieldBlock::Unwin,
+            _ => panic!("the block must be `H`, `P` or `U`"),
+        };
+
+        let floor = match identifier[1] {
+            b'G' => HighfieldFloor::Ground,
+            level @ b'1'..=b'9' => match RangedU8::new(level - b'0') {
+                Some(level) => HighfieldFloor::Level(level),
+                None => unreachable!(),
+            },
+            _ => panic!("the floor must be `G` or a level in the range `1..=9`"),
+        };
+
+        let discriminator = match (identifier[2], identifier[3]) {
+            (tens @ b'0'..=b'9', units @ b'0'..=b'9') => {
+                match RangedU8::new((tens - b'0') * 10 + (units - b'0')) {
+                    Some(discriminator) => discriminator,
+                    None => panic!("the discriminator must be in the range `01..=99`"),
+                }
+            }
+            _ => panic!("the discriminator must be two digits"),
+        };
+
+        Self::Classroom {
+            block,
+            floor,
+            discriminator,
+        }
+    }
+
+    /// Retrieves the block in which the room is located, or [`None`] if the
+    /// room is not a [`HighfieldRoom::Classroom`].
+    pub fn building(&self) -> Option<HighfieldBlock> {
+        match self {
+            Self::Classroom { block, .. } => Some(*block),
+            _ => None,
+        }
+    }
+
+    /// Whether both rooms are classrooms on the same floor of the same block
+    /// (i.e., whether they differ only by their discriminator).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::room;
+    /// assert!(room!(P201).same_floor(&room!(P212)));
+    /// assert!(!room!(P201).same_floor(&room!(P301)));
+    /// ```
+    pub fn same_floor(&self, other: &HighfieldRoom) -> bool {
+        match (self, other) {
+            (
+                Self::Classroom { block, floor, .. },
+                Self::Classroom {
+                    block: other_block,
+                    floor: other_floor,
+                    ..
+                },
+            ) => block == other_block && floor == other_floor,
+            _ => false,
+        }
+    }
+
+    /// Creates a [`HighfieldRoomBuilder`] for a [`HighfieldRoom::Classroom`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::{room, HighfieldBlock, HighfieldRoom};
+    /// let room = HighfieldRoom::builder()
+    ///     .block(HighfieldBlock::Parker)
+    ///     .floor(2)
+    ///     .discriminator(12)
+    ///     .build();
+    ///
+    /// assert_eq!(room, Some(room!(P212)));
+    /// ```
+    pub fn builder() -> HighfieldRoomBuilder {
+        HighfieldRoomBuilder::default()
+    }
+}
+
+/// A builder for a [`HighfieldRoom::Classroom`] (see
+/// [`HighfieldRoom::builder`]).
+///
+/// # Remarks
+///
+/// The components are only validated when the classroom is
+/// [built](HighfieldRoomBuilder::build).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HighfieldRoomBuilder {
+    block: Option<HighfieldBlock>,
+    floor: Option<u8>,
+    discriminator: Option<u8>,
+}
+
+impl HighfieldRoomBuilder {
+    /// Sets the block in which the classroom is located.
+    pub fn block(mut self, block: HighfieldBlock) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the floor on which the classroom is located, where `0` refers to
+    /// the ground floor.
+    pub fn floor(mut self, floor: u8) -> Self {
+        self.floor = Some(floor);
+        self
+    }
+
+    /// Sets the discriminator of the classroom.
+    pub fn discriminator(mut self, discriminator: u8) -> Self {
+        self.discriminator = Some(discriminator);
+        self
+    }
+
+    /// Creates the [`HighfieldRoom::Classroom`].
+    ///
+    /// # Returns
+    ///
+    /// [`None`] if any of the components has not been set, or if the
+    /// components are invalid (see [`HighfieldRoom::classroom`]).
+    pub fn build(self) -> Option<HighfieldRoom> {
+        HighfieldRoom::classroom(self.block?, self.floor?, self.discriminator?)
+    }
+}
+
 impl Display for HighfieldRoom {
     // Format the HighfieldRoom such that it prints its room identifier
     //
@@ -115,10 +360,142 @@ impl Display for HighfieldRoom {
     }
 }
 
+impl FromStr for HighfieldRoom {
+    type Err = ParseLocationError;
+
+    /// Parses a `HighfieldRoom` from its room identifier (the inverse of its
+    /// [`Display`] implementation).
+    ///
+    /// *See the [`crate`] documentation for more information*.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ParseLocationError::*;
+
+        match s {
+            "Hall" => return Ok(Self::Hall),
+            "Sports Hall" => return Ok(Self::SportsHall),
+            _ => {}
+        }
+
+        let mut chars = s.chars();
+
+        let block: HighfieldBlock = chars
+            .next()
+            .ok_or(UnexpectedEnd)?
+            .encode_utf8(&mut [0; 4])
+            .parse()?;
+
+        let floor = chars.next().ok_or(UnexpectedEnd)?;
+        let floor = HighfieldFloor::from_char(floor).ok_or(InvalidFloor(floor))?;
+
+        // The discriminator is always padded to two digits (e.g., `01`), so
+        // any other number of digits is rejected
+        let digits = chars.as_str();
+        let discriminator = match digits.as_bytes() {
+            [tens @ b'0'..=b'9', units @ b'0'..=b'9'] => {
+                RangedU8::new((tens - b'0') * 10 + (units - b'0'))
+            }
+            _ => None,
+        }
+        .ok_or_else(|| InvalidDiscriminator(digits.to_string()))?;
+
+        Ok(Self::Classroom {
+            block,
+            floor,
+            discriminator,
+        })
+    }
+}
+
+impl PartialOrd for HighfieldRoom {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HighfieldRoom {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use HighfieldRoom::*;
+
+        // Named rooms are ranked in the order in which they are declared,
+        // followed by the classrooms
+        let rank = |room: &Self| match room {
+            Hall => 0,
+            SportsHall => 1,
+            Classroom { .. } => 2,
+        };
+
+        match (self, other) {
+            (
+                Classroom {
+                    block,
+                    floor,
+                    discriminator,
+                },
+                Classroom {
+                    block: other_block,
+                    floor: other_floor,
+                    discriminator: other_discriminator,
+                },
+            ) => {
+                (block, floor, discriminator).cmp(&(other_block, other_floor, other_discriminator))
+            }
+            _ => rank(self).cmp(&rank(other)),
+        }
+    }
+}
+
+/// Creates a [`HighfieldRoom`] from its room identifier (e.g., `P212`).
+///
+/// *See the [`crate`] documentation for more information*.
+///
+/// # Examples
+///
+/// ```
+/// # use timetableau::{room, HighfieldBlock, HighfieldRoom};
+/// assert_eq!(room!(P212), HighfieldRoom::classroom(HighfieldBlock::Parker, 2, 12).unwrap());
+/// assert_eq!(room!(HG01), HighfieldRoom::classroom(HighfieldBlock::Howard, 0, 1).unwrap());
+/// assert_eq!(room!(SportsHall), HighfieldRoom::SportsHall);
+/// ```
+///
+/// Invalid identifiers are rejected at compile time:
+///
+/// ```compile_fail
+/// # use timetableau::room;
+/// // The discriminator has three digits
+/// let room = room!(P2123);
+/// ```
+///
+/// # Remarks
+///
+/// Like the [`crate::timeslot!`] macro, the identifier provided **MUST** be
+/// uppercase, and the sports hall must be written `SportsHall`.
+#[macro_export]
+macro_rules! room {
+    (Hall) => {
+        $crate::HighfieldRoom::Hall
+    };
+    (SportsHall) => {
+        $crate::HighfieldRoom::SportsHall
+    };
+    ($identifier:ident) => {{
+        // Evaluate the identifier in a constant such that an invalid
+        // identifier fails to compile
+        const ROOM: $crate::HighfieldRoom =
+            $crate::HighfieldRoom::__classroom(::core::stringify!($identifier));
+        ROOM
+    }};
+}
+
 /// A section at the Fearnhill school.
 ///
 /// *See the [`crate`] documentation for more information*.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// # Ordering
+///
+/// Sections are ordered in the order in which they are declared (which
+/// matches the order of the list in the [`crate`] documentation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FearnhillSection {
     Science,
     Business,
@@ -132,6 +509,28 @@ pub enum FearnhillSection {
     IT,
 }
 
+impl FearnhillSection {
+    /// Retrieves the full name of the subject area of the `FearnhillSection`
+    /// (e.g., `Science` for [`FearnhillSection::Science`], whose identifier is
+    /// `S`).
+    pub const fn subject(self) -> &'static str {
+        use FearnhillSection::*;
+
+        match self {
+            Science => "Science",
+            Business => "Business",
+            PSHE => "PSHE",
+            Languages => "Languages",
+            Technology => "Technology",
+            Mathematics => "Mathematics",
+            English => "English",
+            Music => "Music",
+            Humanities => "Humanities",
+            IT => "IT",
+        }
+    }
+}
+
 impl Display for FearnhillSection {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         use FearnhillSection::*;
@@ -151,10 +550,42 @@ impl Display for FearnhillSection {
     }
 }
 
+impl FromStr for FearnhillSection {
+    type Err = ParseLocationError;
+
+    /// Parses a `FearnhillSection` from its identifier (the inverse of its
+    /// [`Display`] implementation).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use FearnhillSection::*;
+
+        Ok(match s {
+            "S" => Science,
+            "B" => Business,
+            "P" => PSHE,
+            "L" => Languages,
+            "T" => Technology,
+            "M" => Mathematics,
+            "E" => English,
+            "Mu" => Music,
+            "H" => Humanities,
+            "I" => IT,
+            section => return Err(ParseLocationError::InvalidSection(section.to_string())),
+        })
+    }
+}
+
 /// A room at the Fearnhill school.
 ///
 /// *See the [`crate`] documentation for more information*.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// # Ordering
+///
+/// Named rooms (e.g., [`FearnhillRoom::Gym`]) are ordered before
+/// classrooms, in the order in which they are declared. Classrooms are
+/// ordered by their section (see [`FearnhillSection`]), then their
+/// discriminator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 // non_exhaustive is used for two reasons:
 //  1. An exhaustive list of all Fearnhill's rooms has yet to be obtained
 //  2. Fearnhill may add additional rooms at any time (and, as a result,
@@ -189,6 +620,17 @@ pub enum FearnhillRoom {
     },
 }
 
+impl FearnhillRoom {
+    /// Retrieves the section in which the room is located, or [`None`] if the
+    /// room is not a [`FearnhillRoom::Classroom`].
+    pub fn section(&self) -> Option<FearnhillSection> {
+        match self {
+            Self::Classroom { section, .. } => Some(*section),
+            _ => None,
+        }
+    }
+}
+
 impl Display for FearnhillRoom {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         use FearnhillRoom::*;
@@ -209,9 +651,122 @@ impl Display for FearnhillRoom {
     }
 }
 
+impl FromStr for FearnhillRoom {
+    type Err = ParseLocationError;
+
+    /// Parses a `FearnhillRoom` from its room identifier (the inverse of its
+    /// [`Display`] implementation).
+    ///
+    /// *See the [`crate`] documentation for more information*.
+    ///
+    /// # Remarks
+    ///
+    /// As [`Location`] prepends `FH ` to Fearnhill rooms, the room identifier
+    /// may optionally start with `FH ` (e.g., both `FH S13` and `S13` are
+    /// accepted).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ParseLocationError::*;
+
+        let s = s.strip_prefix("FH ").unwrap_or(s);
+
+        match s {
+            "Sports Hall" => return Ok(Self::SportsHall),
+            "Gym" => return Ok(Self::Gym),
+            "Dance Studio" => return Ok(Self::DanceStudio),
+            "Drama Studio" => return Ok(Self::DramaStudio),
+            _ => {}
+        }
+
+        if s.is_empty() {
+            return Err(UnexpectedEnd);
+        }
+
+        // The section is everything before the discriminator -- splitting on
+        // the first digit (rather than taking a single character) ensures
+        // `Mu` (Music) is not mistaken for `M` (Mathematics)
+        let (section, digits) = s.split_at(s.find(|c: char| c.is_ascii_digit()).unwrap_or(s.len()));
+
+        let section: FearnhillSection = section.parse()?;
+
+        // Unlike Highfield, the discriminator is not padded (e.g., `FH B1`),
+        // so leading zeros are rejected
+        let discriminator = Some(digits)
+            .filter(|digits| !digits.starts_with('0'))
+            .and_then(|digits| digits.parse().ok())
+            .and_then(RangedU8::new)
+            .ok_or_else(|| InvalidDiscriminator(digits.to_string()))?;
+
+        Ok(Self::Classroom {
+            section,
+            discriminator,
+        })
+    }
+}
+
+impl PartialOrd for FearnhillRoom {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FearnhillRoom {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use FearnhillRoom::*;
+
+        // Named rooms are ranked in the order in which they are declared,
+        // followed by the classrooms
+        let rank = |room: &Self| match room {
+            SportsHall => 0,
+            Gym => 1,
+            DanceStudio => 2,
+            DramaStudio => 3,
+            Classroom { .. } => 4,
+        };
+
+        match (self, other) {
+            (
+                Classroom {
+                    section,
+                    discriminator,
+                },
+                Classroom {
+                    section: other_section,
+                    discriminator: other_discriminator,
+                },
+            ) => (section, discriminator).cmp(&(other_section, other_discriminator)),
+            _ => rank(self).cmp(&rank(other)),
+        }
+    }
+}
+
 /// A location of a room (in which a lesson can take place) in either the
 /// Highfield school or the Fearnhill school.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// # Ordering
+///
+/// Highfield rooms are ordered before Fearnhill rooms -- *see
+/// [`HighfieldRoom`] and [`FearnhillRoom`] for the ordering of the rooms at
+/// each school*.
+///
+/// # Formatting
+///
+/// The room identifiers of Fearnhill rooms are prefixed with `FH ` (e.g.,
+/// `FH S13`) to distinguish them from Highfield rooms -- the alternate flag
+/// (`{:#}`) omits the prefix for contexts in which the school is already
+/// known.
+///
+/// ```
+/// # use timetableau::Location;
+/// let location: Location = "FH S13".parse().unwrap();
+///
+/// assert_eq!(format!("{location}"), "FH S13");
+/// assert_eq!(format!("{location:#}"), "S13");
+/// ```
+///
+/// As the prefix is required to parse a Fearnhill room as a `Location`,
+/// the alternate form cannot be parsed back into a `Location`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Location {
     /// The location of a room at the Highfield school.
     Highfield(HighfieldRoom),
@@ -220,19 +775,452 @@ pub enum Location {
     Fearnhill(FearnhillRoom),
 }
 
+impl Location {
+    /// Determines whether a student must travel between schools to get from
+    /// this `Location` to the `other` (i.e., whether the two `Location`s are
+    /// at different schools).
+    pub fn requires_travel(&self, other: &Location) -> bool {
+        !matches!(
+            (self, other),
+            (Self::Highfield(_), Self::Highfield(_)) | (Self::Fearnhill(_), Self::Fearnhill(_))
+        )
+    }
+}
+
 impl Display for Location {
+    // The room identifier is written to a buffer such that `pad` can honour
+    // the formatter's width, fill and alignment (e.g., `{:^10}`)
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut buffer = String::new();
+
         match self {
-            Self::Highfield(room) => Display::fmt(room, f),
+            Self::Highfield(room) => write!(buffer, "{room}")?,
+            // The prefix is omitted in the alternate form (`{:#}`)
+            Self::Fearnhill(room) if f.alternate() => write!(buffer, "{room}")?,
             Self::Fearnhill(room) => {
                 // Prepend "FH " to all Fearnhill rooms for disambiguation
                 // For example, both Highfield and Fearnhill have a
                 // "Sports Hall" -- to prevent Fearnhill's sports hall from
                 // being mistaken as Highfield's, format the identifier as
                 // "FH <room identifier>"
-                f.write_str("FH ")?;
-                Display::fmt(room, f)
+                write!(buffer, "FH {room}")?
             }
         }
+
+        f.pad(&buffer)
+    }
+}
+
+impl PartialOrd for Location {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Location {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Highfield(room), Self::Highfield(other)) => room.cmp(other),
+            (Self::Fearnhill(room), Self::Fearnhill(other)) => room.cmp(other),
+            (Self::Highfield(_), Self::Fearnhill(_)) => Ordering::Less,
+            (Self::Fearnhill(_), Self::Highfield(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl From<HighfieldRoom> for Location {
+    fn from(room: HighfieldRoom) -> Self {
+        Self::Highfield(room)
+    }
+}
+
+impl From<FearnhillRoom> for Location {
+    fn from(room: FearnhillRoom) -> Self {
+        Self::Fearnhill(room)
+    }
+}
+
+impl FromStr for Location {
+    type Err = ParseLocationError;
+
+    /// Parses a `Location` from its room identifier (the inverse of its
+    /// [`Display`] implementation).
+    ///
+    /// Room identifiers starting with `FH ` are parsed as
+    /// [`FearnhillRoom`]s, all other room identifiers are parsed as
+    /// [`HighfieldRoom`]s.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("FH ") {
+            s.parse().map(Self::Fearnhill)
+        } else {
+            s.parse().map(Self::Highfield)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classroom(block: HighfieldBlock, floor: u8, discriminator: u8) -> HighfieldRoom {
+        HighfieldRoom::classroom(block, floor, discriminator).unwrap()
+    }
+
+    #[test]
+    fn highfield_floor_ordering() {
+        let ground = HighfieldFloor::Ground;
+        let first = HighfieldFloor::Level(RangedU8::new(1).unwrap());
+        let ninth = HighfieldFloor::Level(RangedU8::new(9).unwrap());
+
+        assert!(ground < first);
+        assert!(first < ninth);
+    }
+
+    #[test]
+    fn location_display_padding() {
+        let highfield: Location = "P212".parse().unwrap();
+        let fearnhill: Location = "FH Mu2".parse().unwrap();
+
+        assert_eq!(format!("{highfield:^8}"), "  P212  ");
+        assert_eq!(format!("{fearnhill:<8}|"), "FH Mu2  |");
+        assert_eq!(format!("{fearnhill:>3}"), "FH Mu2");
+    }
+
+    #[test]
+    fn location_display_alternate() {
+        let fearnhill: Location = "FH Sports Hall".parse().unwrap();
+        let highfield: Location = "Sports Hall".parse().unwrap();
+
+        assert_eq!(format!("{fearnhill}"), "FH Sports Hall");
+        assert_eq!(format!("{fearnhill:#}"), "Sports Hall");
+        assert_eq!(format!("{highfield:#}"), "Sports Hall");
+
+        // The alternate flag can be combined with padding
+        assert_eq!(
+            format!("{:>#5}", "FH B1".parse::<Location>().unwrap()),
+            "   B1"
+        );
+    }
+
+    #[test]
+    fn location_from_room() {
+        assert_eq!(
+            Location::from(room!(P212)),
+            Location::Highfield(room!(P212))
+        );
+        assert_eq!(
+            Location::from(FearnhillRoom::Gym),
+            Location::Fearnhill(FearnhillRoom::Gym)
+        );
+
+        let location: Location = HighfieldRoom::Hall.into();
+        assert_eq!(location, Location::Highfield(HighfieldRoom::Hall));
+    }
+
+    #[test]
+    fn location_ordering() {
+        let mut locations: Vec<Location> = [
+            "FH S13",
+            "P212",
+            "FH Gym",
+            "H101",
+            "Sports Hall",
+            "FH Sports Hall",
+            "FH B1",
+            "HG01",
+            "Hall",
+            "P112",
+            "FH S2",
+        ]
+        .into_iter()
+        .map(|location| location.parse().unwrap())
+        .collect();
+
+        locations.sort();
+
+        assert_eq!(
+            locations
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "Hall",
+                "Sports Hall",
+                "HG01",
+                "H101",
+                "P112",
+                "P212",
+                "FH Sports Hall",
+                "FH Gym",
+                "FH S2",
+                "FH S13",
+                "FH B1",
+            ]
+        );
+    }
+
+    #[test]
+    fn highfield_floor_level() {
+        assert_eq!(HighfieldFloor::from_level(0), Some(HighfieldFloor::Ground));
+        assert_eq!(
+            HighfieldFloor::from_level(5).map(HighfieldFloor::level),
+            Some(5)
+        );
+        assert_eq!(HighfieldFloor::from_level(10), None);
+        assert_eq!(HighfieldFloor::Ground.level(), 0);
+    }
+
+    #[test]
+    fn highfield_floor_from_char() {
+        assert_eq!(HighfieldFloor::from_char('G'), Some(HighfieldFloor::Ground));
+        assert_eq!(
+            HighfieldFloor::from_char('5'),
+            HighfieldFloor::from_level(5)
+        );
+        assert_eq!(HighfieldFloor::from_char('0'), None);
+        assert_eq!(HighfieldFloor::from_char('a'), None);
+        assert_eq!(HighfieldFloor::from_char('g'), None);
+
+        // `from_char` is the inverse of the `Display` implementation
+        for level in 0..=9 {
+            let floor = HighfieldFloor::from_level(level).unwrap();
+            let identifier = floor.to_string().chars().next().unwrap();
+
+            assert_eq!(HighfieldFloor::from_char(identifier), Some(floor));
+        }
+    }
+
+    #[test]
+    fn highfield_room_same_floor() {
+        assert!(room!(P201).same_floor(&room!(P212)));
+        assert!(!room!(P201).same_floor(&room!(P301)));
+        assert!(!room!(P201).same_floor(&room!(H201)));
+
+        // Named rooms are not on a floor
+        assert!(!HighfieldRoom::Hall.same_floor(&HighfieldRoom::Hall));
+        assert!(!HighfieldRoom::Hall.same_floor(&room!(P201)));
+    }
+
+    #[test]
+    fn highfield_room_builder() {
+        let builder = HighfieldRoom::builder()
+            .block(HighfieldBlock::Unwin)
+            .floor(0);
+
+        assert_eq!(
+            builder.discriminator(7).build(),
+            Some(classroom(HighfieldBlock::Unwin, 0, 7))
+        );
+
+        // Invalid components
+        assert_eq!(builder.discriminator(0).build(), None);
+        assert_eq!(builder.discriminator(100).build(), None);
+        assert_eq!(builder.floor(10).discriminator(7).build(), None);
+
+        // Missing components
+        assert_eq!(builder.build(), None);
+        assert_eq!(
+            HighfieldRoom::builder().floor(1).discriminator(1).build(),
+            None
+        );
+    }
+
+    #[test]
+    fn highfield_room_classroom() {
+        use HighfieldBlock::*;
+
+        assert_eq!(
+            HighfieldRoom::classroom(Howard, 0, 1),
+            Some(HighfieldRoom::Classroom {
+                block: Howard,
+                floor: HighfieldFloor::Ground,
+                discriminator: RangedU8::new(1).unwrap(),
+            })
+        );
+        assert_eq!(
+            HighfieldRoom::classroom(Parker, 9, 99),
+            Some(HighfieldRoom::Classroom {
+                block: Parker,
+                floor: HighfieldFloor::Level(RangedU8::new(9).unwrap()),
+                discriminator: RangedU8::new(99).unwrap(),
+            })
+        );
+        assert_eq!(HighfieldRoom::classroom(Parker, 10, 12), None);
+        assert_eq!(HighfieldRoom::classroom(Parker, 2, 0), None);
+        assert_eq!(HighfieldRoom::classroom(Parker, 2, 100), None);
+    }
+
+    #[test]
+    fn highfield_block_parse() {
+        assert_eq!("H".parse(), Ok(HighfieldBlock::Howard));
+        assert_eq!("U".parse(), Ok(HighfieldBlock::Unwin));
+        assert_eq!(
+            "HG".parse::<HighfieldBlock>(),
+            Err(ParseLocationError::InvalidBlock("HG".to_string()))
+        );
+    }
+
+    #[test]
+    fn fearnhill_section_parse() {
+        assert_eq!("Mu".parse(), Ok(FearnhillSection::Music));
+        assert_eq!("M".parse(), Ok(FearnhillSection::Mathematics));
+        assert_eq!(
+            "Ma".parse::<FearnhillSection>(),
+            Err(ParseLocationError::InvalidSection("Ma".to_string()))
+        );
+    }
+
+    #[test]
+    fn fearnhill_section_subject() {
+        assert_eq!(FearnhillSection::Science.subject(), "Science");
+        assert_eq!(FearnhillSection::PSHE.subject(), "PSHE");
+        assert_eq!("Mu".parse::<FearnhillSection>().unwrap().subject(), "Music");
+    }
+
+    #[test]
+    fn highfield_room_parse_valid() {
+        use HighfieldBlock::*;
+
+        assert_eq!("P212".parse(), Ok(classroom(Parker, 2, 12)));
+        assert_eq!("HG01".parse(), Ok(classroom(Howard, 0, 1)));
+        assert_eq!("U199".parse(), Ok(classroom(Unwin, 1, 99)));
+        assert_eq!("Hall".parse(), Ok(HighfieldRoom::Hall));
+        assert_eq!("Sports Hall".parse(), Ok(HighfieldRoom::SportsHall));
+    }
+
+    #[test]
+    fn highfield_room_parse_invalid() {
+        use ParseLocationError::*;
+
+        assert_eq!("".parse::<HighfieldRoom>(), Err(UnexpectedEnd));
+        assert_eq!(
+            "X212".parse::<HighfieldRoom>(),
+            Err(InvalidBlock("X".to_string()))
+        );
+        assert_eq!("P012".parse::<HighfieldRoom>(), Err(InvalidFloor('0')));
+        assert_eq!(
+            "P2123".parse::<HighfieldRoom>(),
+            Err(InvalidDiscriminator("123".to_string()))
+        );
+        assert_eq!(
+            "P200".parse::<HighfieldRoom>(),
+            Err(InvalidDiscriminator("00".to_string()))
+        );
+    }
+
+    #[test]
+    fn highfield_room_round_trip() {
+        use HighfieldBlock::*;
+
+        for room in [
+            classroom(Howard, 0, 4),
+            classroom(Parker, 9, 10),
+            HighfieldRoom::Hall,
+            HighfieldRoom::SportsHall,
+        ] {
+            assert_eq!(room.to_string().parse(), Ok(room));
+        }
+    }
+
+    fn fearnhill_classroom(section: FearnhillSection, discriminator: u8) -> FearnhillRoom {
+        FearnhillRoom::Classroom {
+            section,
+            discriminator: RangedU8::new(discriminator).unwrap(),
+        }
+    }
+
+    #[test]
+    fn fearnhill_room_parse_valid() {
+        use FearnhillSection::*;
+
+        assert_eq!("FH S13".parse(), Ok(fearnhill_classroom(Science, 13)));
+        assert_eq!("B1".parse(), Ok(fearnhill_classroom(Business, 1)));
+        assert_eq!("FH Gym".parse(), Ok(FearnhillRoom::Gym));
+        assert_eq!("Sports Hall".parse(), Ok(FearnhillRoom::SportsHall));
+        assert_eq!("FH Dance Studio".parse(), Ok(FearnhillRoom::DanceStudio));
+        assert_eq!("Drama Studio".parse(), Ok(FearnhillRoom::DramaStudio));
+    }
+
+    #[test]
+    fn fearnhill_room_parse_music_mathematics() {
+        use FearnhillSection::*;
+
+        assert_eq!("FH Mu2".parse(), Ok(fearnhill_classroom(Music, 2)));
+        assert_eq!("FH M2".parse(), Ok(fearnhill_classroom(Mathematics, 2)));
+        assert_eq!("Mu12".parse(), Ok(fearnhill_classroom(Music, 12)));
+        assert_eq!("M12".parse(), Ok(fearnhill_classroom(Mathematics, 12)));
+    }
+
+    #[test]
+    fn fearnhill_room_parse_invalid() {
+        use ParseLocationError::*;
+
+        assert_eq!("FH ".parse::<FearnhillRoom>(), Err(UnexpectedEnd));
+        assert_eq!(
+            "FH X1".parse::<FearnhillRoom>(),
+            Err(InvalidSection("X".to_string()))
+        );
+        assert_eq!(
+            "FH Mu".parse::<FearnhillRoom>(),
+            Err(InvalidDiscriminator(String::new()))
+        );
+        assert_eq!(
+            "FH S01".parse::<FearnhillRoom>(),
+            Err(InvalidDiscriminator("01".to_string()))
+        );
+        assert_eq!(
+            "FH S100".parse::<FearnhillRoom>(),
+            Err(InvalidDiscriminator("100".to_string()))
+        );
+    }
+
+    #[test]
+    fn location_round_trip() {
+        let locations = [
+            Location::Highfield(classroom(HighfieldBlock::Parker, 2, 12)),
+            Location::Highfield(HighfieldRoom::SportsHall),
+            Location::Fearnhill(FearnhillRoom::Gym),
+            Location::Fearnhill(FearnhillRoom::SportsHall),
+            Location::Fearnhill(fearnhill_classroom(FearnhillSection::Music, 2)),
+        ];
+
+        for location in locations {
+            assert_eq!(location.to_string().parse(), Ok(location));
+        }
+    }
+
+    #[test]
+    fn room_macro() {
+        assert_eq!(room!(P212), classroom(HighfieldBlock::Parker, 2, 12));
+        assert_eq!(room!(UG99), classroom(HighfieldBlock::Unwin, 0, 99));
+        assert_eq!(room!(H901), classroom(HighfieldBlock::Howard, 9, 1));
+        assert_eq!(room!(Hall), HighfieldRoom::Hall);
+        assert_eq!(room!(SportsHall), HighfieldRoom::SportsHall);
+    }
+
+    #[test]
+    fn room_building_section() {
+        let classroom = classroom(HighfieldBlock::Unwin, 1, 4);
+        let fearnhill_classroom = fearnhill_classroom(FearnhillSection::Music, 2);
+
+        assert_eq!(classroom.building(), Some(HighfieldBlock::Unwin));
+        assert_eq!(HighfieldRoom::Hall.building(), None);
+        assert_eq!(HighfieldRoom::SportsHall.building(), None);
+
+        assert_eq!(fearnhill_classroom.section(), Some(FearnhillSection::Music));
+        assert_eq!(FearnhillRoom::Gym.section(), None);
+        assert_eq!(FearnhillRoom::DramaStudio.section(), None);
+    }
+
+    #[test]
+    fn location_requires_travel() {
+        let highfield = Location::Highfield(classroom(HighfieldBlock::Parker, 2, 12));
+        let fearnhill = Location::Fearnhill(FearnhillRoom::SportsHall);
+
+        assert!(highfield.requires_travel(&fearnhill));
+        assert!(fearnhill.requires_travel(&highfield));
+
+        assert!(!highfield.requires_travel(&Location::Highfield(HighfieldRoom::Hall)));
+        assert!(!fearnhill.requires_travel(&Location::Fearnhill(FearnhillRoom::Gym)));
     }
 }
@@ -7,11 +7,17 @@ macro_rules! ranged_types {
             /// range of possible values -- in this case, the value must be in the range
             /// `MIN..=MAX`.
             $(#[$attr])*
-            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
             #[repr(transparent)] /* use the same representation as a normal type */
             pub struct $name<const MIN: $type, const MAX: $type>($type);
 
             impl<const MIN: $type, const MAX: $type> $name<MIN, MAX> {
+                /// The smallest value the ranged integer can take (i.e., `MIN`).
+                pub const MIN_VALUE: $type = MIN;
+
+                /// The largest value the ranged integer can take (i.e., `MAX`).
+                pub const MAX_VALUE: $type = MAX;
+
                 #[doc = concat!("Creates a new `", stringify!($name), "<MIN, MAX>`.")]
                 ///
                 /// # Returns
@@ -35,10 +41,49 @@ macro_rules! ranged_types {
                     }
                 }
 
+                #[doc = concat!("Creates a new `", stringify!($name), "<MIN, MAX>`, clamping the `value`")]
+                /// to the range `MIN..=MAX` (i.e., values below `MIN` become `MIN`, and
+                /// values above `MAX` become `MAX`).
+                pub const fn new_saturating(value: $type) -> Self {
+                    if value < MIN {
+                        Self(MIN)
+                    } else if value > MAX {
+                        Self(MAX)
+                    } else {
+                        Self(value)
+                    }
+                }
+
                 #[doc = concat!("Get the inner value of the `", stringify!($name), "`.")]
                 pub const fn get(self) -> $type {
                     self.0
                 }
+
+                /// Adds `rhs` to the value.
+                ///
+                /// # Returns
+                ///
+                /// [`None`] if the result is outside the range `MIN..=MAX` (or
+                #[doc = concat!("cannot be represented by a `", stringify!($type), "`).")]
+                pub const fn checked_add(self, rhs: $type) -> Option<Self> {
+                    match self.0.checked_add(rhs) {
+                        Some(value) => Self::new(value),
+                        None => None,
+                    }
+                }
+
+                /// Subtracts `rhs` from the value.
+                ///
+                /// # Returns
+                ///
+                /// [`None`] if the result is outside the range `MIN..=MAX` (or
+                #[doc = concat!("cannot be represented by a `", stringify!($type), "`).")]
+                pub const fn checked_sub(self, rhs: $type) -> Option<Self> {
+                    match self.0.checked_sub(rhs) {
+                        Some(value) => Self::new(value),
+                        None => None,
+                    }
+                }
             }
 
             impl<const MIN: $type, const MAX: $type> ::core::convert::From<$name<MIN, MAX>> for $type {
@@ -48,10 +93,10 @@ macro_rules! ranged_types {
             }
 
             impl<const MIN: $type, const MAX: $type> ::core::convert::TryFrom<$type> for $name<MIN, MAX> {
-                type Error = ();
+                type Error = $crate::OutOfRangeError;
 
                 fn try_from(value: $type) -> ::core::result::Result<Self, Self::Error> {
-                    Self::new(value).ok_or(())
+                    Self::new(value).ok_or($crate::OutOfRangeError)
                 }
             }
 
@@ -60,6 +105,34 @@ macro_rules! ranged_types {
                     self.0.fmt(f)
                 }
             }
+
+            #[cfg(feature = "serde")]
+            impl<const MIN: $type, const MAX: $type> ::serde::Serialize for $name<MIN, MAX> {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: ::serde::Serializer,
+                {
+                    self.0.serialize(serializer)
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<'de, const MIN: $type, const MAX: $type> ::serde::Deserialize<'de> for $name<MIN, MAX> {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: ::serde::Deserializer<'de>,
+                {
+                    // Ensure the deserialized value is within the range `MIN..=MAX`
+                    let value = <$type as ::serde::Deserialize>::deserialize(deserializer)?;
+
+                    Self::new(value).ok_or_else(|| {
+                        <D::Error as ::serde::de::Error>::custom(::core::format_args!(
+                            "`{}` is outside the range `{}..={}`",
+                            value, MIN, MAX
+                        ))
+                    })
+                }
+            }
         )+
     }
 }
@@ -82,3 +155,60 @@ ranged_types!(
     #[doc(hidden)] RangedI64(i64);
     #[doc(hidden)] RangedIsize(isize);
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranged_display() {
+        assert_eq!(format!("{}", RangedU8::<1, 9>::new(5).unwrap()), "5");
+        assert_eq!(format!("{:0>2}", RangedU8::<1, 99>::new(7).unwrap()), "07");
+    }
+
+    #[test]
+    fn ranged_min_max_value() {
+        assert_eq!(RangedU8::<1, 99>::MIN_VALUE, 1);
+        assert_eq!(RangedU8::<1, 99>::MAX_VALUE, 99);
+        assert_eq!(RangedI8::<-5, 5>::MIN_VALUE, -5);
+    }
+
+    #[test]
+    fn ranged_hash_set() {
+        use std::collections::HashSet;
+
+        let mut discriminators = HashSet::new();
+
+        assert!(discriminators.insert(RangedU8::<1, 99>::new(12).unwrap()));
+        assert!(discriminators.insert(RangedU8::<1, 99>::new(13).unwrap()));
+        assert!(!discriminators.insert(RangedU8::<1, 99>::new(12).unwrap()));
+
+        assert_eq!(discriminators.len(), 2);
+        assert!(discriminators.contains(&RangedU8::new(13).unwrap()));
+    }
+
+    #[test]
+    fn ranged_new_saturating() {
+        assert_eq!(RangedU8::<1, 99>::new_saturating(0).get(), 1);
+        assert_eq!(RangedU8::<1, 99>::new_saturating(50).get(), 50);
+        assert_eq!(RangedU8::<1, 99>::new_saturating(200).get(), 99);
+    }
+
+    #[test]
+    fn ranged_checked_add() {
+        let value = RangedU8::<1, 99>::new(98).unwrap();
+
+        assert_eq!(value.checked_add(1), RangedU8::new(99));
+        assert_eq!(value.checked_add(2), None);
+        assert_eq!(value.checked_add(u8::MAX), None);
+    }
+
+    #[test]
+    fn ranged_checked_sub() {
+        let value = RangedU8::<1, 99>::new(2).unwrap();
+
+        assert_eq!(value.checked_sub(1), RangedU8::new(1));
+        assert_eq!(value.checked_sub(2), None);
+        assert_eq!(value.checked_sub(3), None);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{Activity, Timetable, Week};
+use chrono::{DateTime, NaiveDate, TimeZone};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A [`Timetable`] which is followed from a known date, such that the
+/// [`Activity`] at any date and time can be determined.
+///
+/// *See the [`crate`] documentation for more information*.
+///
+/// # Remarks
+///
+/// The timetable is assumed to alternate every week since (or until) the
+/// `anchor_week_one_monday` -- *see [`Week::from_date`] for more
+/// information*.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Schedule {
+    /// The timetable which is followed.
+    pub timetable: Timetable,
+
+    /// The Monday of a week which is known to be [`Week::One`].
+    pub anchor_week_one_monday: NaiveDate,
+}
+
+impl Schedule {
+    /// Creates a new `Schedule` which follows the `timetable`, where the
+    /// week starting on `anchor_week_one_monday` is [`Week::One`].
+    pub fn new(timetable: Timetable, anchor_week_one_monday: NaiveDate) -> Self {
+        Self {
+            timetable,
+            anchor_week_one_monday,
+        }
+    }
+
+    /// Retrieves the [`Activity`] which takes place at the `datetime`.
+    ///
+    /// # Returns
+    ///
+    /// [`None`] if the `datetime` does not take place during a
+    /// [`TimeSlot`](crate::TimeSlot) (e.g., at a weekend or outside of
+    /// school hours), or if no [`Activity`] has been assigned to its
+    /// [`TimeSlot`](crate::TimeSlot).
+    pub fn activity_at<Tz>(&self, datetime: DateTime<Tz>) -> Option<&Activity>
+    where
+        Tz: TimeZone,
+    {
+        let week = Week::from_date(self.anchor_week_one_monday, datetime.date_naive());
+
+        self.timetable.resolve(week, datetime, &[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timeslot;
+    use chrono::Utc;
+
+    #[test]
+    fn schedule_activity_at() {
+        let lesson = Activity::try_lesson("Maths", "Jones", "P212").unwrap();
+
+        let mut timetable = Timetable::default();
+        timetable.set(timeslot!(W2RP4), lesson.clone());
+
+        let schedule = Schedule::new(timetable, NaiveDate::from_ymd_opt(2024, 3, 4).unwrap());
+        let datetime =
+            |day, hour, minute| Utc.with_ymd_and_hms(2024, 3, day, hour, minute, 0).unwrap();
+
+        // A week two Thursday during the fourth period
+        assert_eq!(schedule.activity_at(datetime(14, 12, 30)), Some(&lesson));
+
+        // The same time during week one
+        assert_eq!(schedule.activity_at(datetime(7, 12, 30)), None);
+
+        // A weekend
+        assert_eq!(schedule.activity_at(datetime(16, 12, 30)), None);
+
+        // Outside of school hours
+        assert_eq!(schedule.activity_at(datetime(14, 18, 0)), None);
+    }
+}
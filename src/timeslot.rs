@@ -1,13 +1,22 @@
-use crate::{Activity, RangedUsize};
+#[cfg(feature = "chrono")]
+use crate::{DateTimeError, InactiveDayError};
+use crate::{IndexOutOfRangeError, ParseTimeSlotError, RangedU8, RangedUsize};
 #[cfg(feature = "chrono")]
 use chrono::prelude::*;
-use num_traits::FromPrimitive;
-use std::fmt::Debug;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::str::FromStr;
+use num_traits::{FromPrimitive, ToPrimitive};
+#[cfg(feature = "serde-index")]
+use serde::{de, Deserializer, Serializer};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// The week of a alternating two-week timetable.
 ///
 /// *See the [`crate`] documentation for more information*.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Week {
     // Assign the variants integer values such that they can be cast into
     // integers (for mathematical purposes)
@@ -18,12 +27,100 @@ pub enum Week {
 impl Week {
     /// The number of `Week`s per iteration of the timetable.
     pub const PER_ITERATION: usize = 2;
+
+    /// Retrieves the opposite `Week` (i.e., the `Week` which follows, and
+    /// precedes, this `Week`).
+    pub const fn other(self) -> Self {
+        match self {
+            Self::One => Self::Two,
+            Self::Two => Self::One,
+        }
+    }
+
+    /// Replaces the `Week` with the [opposite `Week`](Week::other).
+    pub fn toggle(&mut self) {
+        *self = self.other();
+    }
+
+    /// Determines the `Week` in which the `date` occurs, given the Monday of
+    /// a week which is known to be [`Week::One`].
+    ///
+    /// # Remarks
+    ///
+    /// The `Week` is determined by the number of whole weeks between the
+    /// `anchor_week_one_monday` and the `date` -- as such, the result will
+    /// only be correct if the timetable has alternated every week since (or
+    /// until) the anchor (i.e., holidays are not accounted for).
+    ///
+    /// If the `anchor_week_one_monday` is not a Monday, the Monday of its
+    /// week is used instead (i.e., every day of the anchor's week is in
+    /// [`Week::One`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # use timetableau::Week;
+    /// let anchor = NaiveDate::from_ymd_opt(2024, 9, 2).unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2024, 9, 11).unwrap();
+    ///
+    /// assert_eq!(Week::from_date(anchor, date), Week::Two);
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn from_date(anchor_week_one_monday: NaiveDate, date: NaiveDate) -> Self {
+        // Whole weeks are only counted correctly from the start of a week
+        let anchor = anchor_week_one_monday.week(Weekday::Mon).first_day();
+
+        // Euclidean division is used such that dates before the anchor are
+        // placed in the correct week (e.g., the day before the anchor is in
+        // week -1, not week 0)
+        let weeks = (date - anchor).num_days().div_euclid(7);
+
+        if weeks.rem_euclid(2) == 0 {
+            Self::One
+        } else {
+            Self::Two
+        }
+    }
+}
+
+impl FromPrimitive for Week {
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::from_u64(n.try_into().ok()?)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(match n {
+            0 => Self::One,
+            1 => Self::Two,
+            _ => return None,
+        })
+    }
+}
+
+impl ToPrimitive for Week {
+    fn to_i64(&self) -> Option<i64> {
+        Some(*self as i64)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(*self as u64)
+    }
+}
+
+impl From<Week> for usize {
+    /// Retrieves the discriminant of the `Week` (i.e., `0` for [`Week::One`] and `1` for
+    /// [`Week::Two`]).
+    fn from(value: Week) -> Self {
+        value as usize
+    }
 }
 
 /// An active day in a [`Week`].
 ///
 /// *See the [`crate`] documentation for more information*.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ActiveDay {
     Monday = 0,
     Tuesday = 1,
@@ -36,6 +133,16 @@ impl ActiveDay {
     /// The number of `ActiveDay`s per [`Week`].
     pub const PER_WEEK: usize = 5;
 
+    /// Every `ActiveDay` in chronological order (i.e., from
+    /// [`ActiveDay::Monday`] to [`ActiveDay::Friday`]).
+    pub const ALL: [ActiveDay; Self::PER_WEEK] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+    ];
+
     /// The number of days from [`ActiveDay::Monday`].
     ///
     /// | Day                  | Monday | Tuesday | Wednesday | Thursday | Friday |
@@ -44,6 +151,59 @@ impl ActiveDay {
     pub fn num_days_from_monday(self) -> usize {
         self as usize
     }
+
+    /// Retrieves the full name of the `ActiveDay` (e.g., `Wednesday` for
+    /// [`ActiveDay::Wednesday`]).
+    pub const fn name(self) -> &'static str {
+        use ActiveDay::*;
+
+        match self {
+            Monday => "Monday",
+            Tuesday => "Tuesday",
+            Wednesday => "Wednesday",
+            Thursday => "Thursday",
+            Friday => "Friday",
+        }
+    }
+
+    /// Retrieves an iterator over every `ActiveDay` in chronological order
+    /// (i.e., from [`ActiveDay::Monday`] to [`ActiveDay::Friday`]).
+    pub fn iter() -> impl Iterator<Item = ActiveDay> {
+        Self::ALL.into_iter()
+    }
+
+    /// Creates an `ActiveDay` from its WDF letter (see the [`crate`]
+    /// documentation), returning [`None`] if the letter does not refer to an
+    /// active day.
+    fn from_wdf(letter: char) -> Option<Self> {
+        use ActiveDay::*;
+
+        Some(match letter {
+            'M' => Monday,
+            'T' => Tuesday,
+            'W' => Wednesday,
+            // `T` is already used by Tuesday
+            'R' => Thursday,
+            'F' => Friday,
+
+            // Saturday and Sunday (`S`) are not active days
+            _ => return None,
+        })
+    }
+
+    /// Retrieves the WDF letter of the `ActiveDay` (see the [`crate`]
+    /// documentation).
+    fn wdf(self) -> char {
+        use ActiveDay::*;
+
+        match self {
+            Monday => 'M',
+            Tuesday => 'T',
+            Wednesday => 'W',
+            Thursday => 'R',
+            Friday => 'F',
+        }
+    }
 }
 
 impl FromPrimitive for ActiveDay {
@@ -74,6 +234,24 @@ impl FromPrimitive for ActiveDay {
     }
 }
 
+impl ToPrimitive for ActiveDay {
+    fn to_i64(&self) -> Option<i64> {
+        Some(*self as i64)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(*self as u64)
+    }
+}
+
+impl From<ActiveDay> for usize {
+    /// Retrieves the number of days from [`ActiveDay::Monday`] (see
+    /// [`ActiveDay::num_days_from_monday`]).
+    fn from(value: ActiveDay) -> Self {
+        value as usize
+    }
+}
+
 #[cfg(feature = "chrono")]
 impl From<ActiveDay> for Weekday {
     fn from(active_day: ActiveDay) -> Self {
@@ -91,7 +269,7 @@ impl From<ActiveDay> for Weekday {
 
 #[cfg(feature = "chrono")]
 impl TryFrom<Weekday> for ActiveDay {
-    type Error = ();
+    type Error = InactiveDayError;
 
     fn try_from(weekday: Weekday) -> Result<Self, Self::Error> {
         use Weekday::*;
@@ -104,7 +282,7 @@ impl TryFrom<Weekday> for ActiveDay {
             Fri => ActiveDay::Friday,
 
             // Weekday::Sat and Weekday::Sun are not active days
-            _ => return Err(()),
+            _ => return Err(InactiveDayError(weekday)),
         })
     }
 }
@@ -112,7 +290,14 @@ impl TryFrom<Weekday> for ActiveDay {
 /// A period for an [`ActiveDay`].
 ///
 /// *See the [`crate`] documentation for more information*.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// # Ordering
+///
+/// Periods are ordered chronologically (i.e., [`Period::Tutor`] is ordered
+/// before every other period and [`Period::Fifth`] after every other period).
+// The derived ordering is used as the variants are declared chronologically
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Period {
     // Assign the variants integer values such that they can be cast into
     // integers (for mathematical purposes)
@@ -120,7 +305,7 @@ pub enum Period {
     ///
     /// # Remarks
     ///
-    /// This is typically used for the [registration activity](Activity::Registration).
+    /// This is typically used for the [registration activity](crate::Activity::Registration).
     Tutor = 0,
 
     /// The first `Period` in a day taking place between `08:50` and
@@ -149,6 +334,10 @@ pub enum Period {
     Fifth = 7,
 }
 
+// The discriminants of `Period` are consecutive (starting at `0`), so the
+// discriminant of the last `Period` determines the number of variants
+const _: () = assert!(Period::Fifth as usize + 1 == Period::PER_DAY);
+
 impl Period {
     /// The number of `Period`s per [`ActiveDay`].
     pub const PER_DAY: usize = 8;
@@ -159,6 +348,218 @@ impl Period {
     /// The number of `Period`s per iteration of the timetable.
     pub const PER_ITERATION: usize = Self::PER_WEEK * Week::PER_ITERATION;
 
+    /// The start and (exclusive) end of every `Period` as the number of
+    /// minutes since midnight, in chronological order.
+    ///
+    /// *See the [`crate`] documentation for more information*.
+    ///
+    /// # Remarks
+    ///
+    /// Each `Period` is stored at its index (see [`Period::index`]), and ends
+    /// at the start of the `Period` which follows it (e.g., [`Period::First`]
+    /// ends at `590`, or `09:50`, which is when [`Period::Second`] starts).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::Period;
+    /// let (period, start, end) = Period::BOUNDARIES[Period::Third.index()];
+    ///
+    /// assert_eq!(period, Period::Third);
+    /// assert_eq!((start / 60, start % 60), (11, 10));
+    /// assert_eq!((end / 60, end % 60), (12, 10));
+    /// ```
+    pub const BOUNDARIES: [(Period, u32, u32); Self::PER_DAY] = [
+        // 08:25 to 08:50
+        (Period::Tutor, 505, 530),
+        // 08:50 to 09:50
+        (Period::First, 530, 590),
+        // 09:50 to 10:50
+        (Period::Second, 590, 650),
+        // 10:50 to 11:10
+        (Period::Break, 650, 670),
+        // 11:10 to 12:10
+        (Period::Third, 670, 730),
+        // 12:10 to 13:10
+        (Period::Fourth, 730, 790),
+        // 13:10 to 13:55
+        (Period::Lunch, 790, 835),
+        // 13:55 to 14:55
+        (Period::Fifth, 835, 895),
+    ];
+
+    /// Retrieves an iterator over every `Period` in chronological order (i.e.,
+    /// from [`Period::Tutor`] to [`Period::Fifth`]).
+    pub fn iter() -> impl Iterator<Item = Period> {
+        (0..Self::PER_DAY).map(|index| Self::from_usize(index).unwrap())
+    }
+
+    /// Creates a `Period` from its index in a day (i.e., its position in
+    /// [`Period::iter`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::{Period, RangedU8};
+    /// assert_eq!(Period::with_index(RangedU8::new(3).unwrap()), Period::Break);
+    /// ```
+    pub fn with_index(index: RangedU8<0, { Self::PER_DAY as u8 - 1 }>) -> Self {
+        Self::from_u8(index.get()).unwrap()
+    }
+
+    /// Creates a `Period` from its index in a day (i.e., its position in
+    /// [`Period::iter`]).
+    ///
+    /// # Errors
+    ///
+    /// An [`IndexOutOfRangeError`] is returned if the `index` is not less
+    /// than [`Period::PER_DAY`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::Period;
+    /// assert_eq!(Period::from_index(7), Ok(Period::Fifth));
+    /// assert!(Period::from_index(8).is_err());
+    /// ```
+    pub fn from_index(index: usize) -> Result<Self, IndexOutOfRangeError> {
+        Self::from_usize(index).ok_or(IndexOutOfRangeError {
+            index,
+            len: Self::PER_DAY,
+        })
+    }
+
+    /// Retrieves the index of the `Period` in a day (i.e., its position in
+    /// [`Period::iter`]).
+    ///
+    /// # Remarks
+    ///
+    /// The value returned will always be in the range `0..Period::PER_DAY`.
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Retrieves an iterator over the five numbered periods in chronological
+    /// order (i.e., from [`Period::First`] to [`Period::Fifth`]).
+    pub fn teaching_periods() -> impl Iterator<Item = Period> {
+        Self::iter().filter(|period| period.is_teaching())
+    }
+
+    /// Retrieves the next [teaching period](Period::is_teaching) after this
+    /// `Period` on the same day, skipping any recesses (e.g., the next
+    /// teaching period after [`Period::Second`] is [`Period::Third`]).
+    ///
+    /// [`None`] is returned if there are no teaching periods after
+    /// this `Period` (i.e., for [`Period::Fifth`]).
+    pub fn next_teaching(self) -> Option<Self> {
+        Self::teaching_periods().find(|period| *period > self)
+    }
+
+    /// Retrieves the previous [teaching period](Period::is_teaching) before
+    /// this `Period` on the same day, skipping any recesses (e.g., the
+    /// previous teaching period before [`Period::Third`] is
+    /// [`Period::Second`]).
+    ///
+    /// [`None`] is returned if there are no teaching periods before this
+    /// `Period` (i.e., for [`Period::Tutor`] and [`Period::First`]).
+    pub fn prev_teaching(self) -> Option<Self> {
+        Self::teaching_periods()
+            .take_while(|period| *period < self)
+            .last()
+    }
+
+    /// Whether the `other` period immediately follows this `Period` without
+    /// a recess between them (e.g., [`Period::First`] is adjacent to
+    /// [`Period::Second`], but [`Period::Second`] is not adjacent to
+    /// [`Period::Third`] as [`Period::Break`] is between them).
+    ///
+    /// # Remarks
+    ///
+    /// Only [teaching periods](Period::is_teaching) can be adjacent (i.e., a
+    /// recess or [`Period::Tutor`] is not adjacent to any `Period`), such
+    /// that two periods are adjacent if and only if they can form a
+    /// [`PeriodRange`] (and, therefore, be spanned by an
+    /// [`ActivitySpan`](crate::ActivitySpan)).
+    pub fn is_adjacent(self, other: Period) -> bool {
+        self.index() + 1 == other.index() && self.is_teaching() && other.is_teaching()
+    }
+
+    /// Whether the `Period` is one of the five numbered periods (i.e., one in
+    /// which lessons are taught).
+    pub fn is_teaching(self) -> bool {
+        use Period::*;
+
+        matches!(self, First | Second | Third | Fourth | Fifth)
+    }
+
+    /// Whether the `Period` is a recess (i.e., [`Period::Break`] or
+    /// [`Period::Lunch`]).
+    pub fn is_break(self) -> bool {
+        matches!(self, Period::Break | Period::Lunch)
+    }
+
+    /// Retrieves the label of the `Period` used in the example timetable in the
+    /// [`crate`] documentation (e.g., `First` for [`Period::First`], and
+    /// `BREAK` for [`Period::Break`]).
+    ///
+    /// # Remarks
+    ///
+    /// The labels of [`Period::Tutor`], [`Period::Break`] and [`Period::Lunch`]
+    /// are capitalised such that they stand out from the teaching periods.
+    pub const fn label(self) -> &'static str {
+        use Period::*;
+
+        match self {
+            Tutor => "TUTOR",
+            First => "First",
+            Second => "Second",
+            Break => "BREAK",
+            Third => "Third",
+            Fourth => "Fourth",
+            Lunch => "LUNCH",
+            Fifth => "Fifth",
+        }
+    }
+
+    /// Whether the `Period` is tutor time.
+    pub fn is_tutor(self) -> bool {
+        self == Period::Tutor
+    }
+
+    /// Creates a new `Period` based on the `hour` and `minute` provided (see
+    /// [`Period::from_time`]) -- unlike [`Period::from_time`], this does not
+    /// require the `chrono` feature.
+    ///
+    /// # Returns
+    ///
+    /// [`None`] if the time does not correspond to a `Period`, or if the
+    /// `hour` is greater than `23` or the `minute` is greater than `59`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::Period;
+    /// assert_eq!(Period::from_hm(11, 10), Some(Period::Third));
+    /// assert_eq!(Period::from_hm(8, 24), None);
+    /// ```
+    pub fn from_hm(hour: u8, minute: u8) -> Option<Self> {
+        if hour > 23 || minute > 59 {
+            return None;
+        }
+
+        // `hour * 60 + minute` calculates the number of minutes the time is
+        // into the day (i.e., the number of minutes since midnight) -- this
+        // is done such that we can easily match time ranges (i.e., whether a
+        // particular time occurs between two other times)
+        let minutes = u32::from(hour) * 60 + u32::from(minute);
+
+        // Note: the end of each period is excluded
+        Self::BOUNDARIES
+            .iter()
+            .find(|(_, start, end)| (*start..*end).contains(&minutes))
+            .map(|(period, _, _)| *period)
+    }
+
     /// Creates a new `Period` based on the `time` provided -- if the `time`
     /// provided corresponds to a `Period`, that `Period` will be returned,
     /// otherwise [`None`] will be returned.
@@ -166,62 +567,237 @@ impl Period {
     /// *See the [`crate`] documentation for more information*.
     #[cfg(feature = "chrono")]
     pub fn from_time(time: NaiveTime) -> Option<Self> {
-        // `time.hour() * 60 + time.minute()` calculates the number of
-        // minutes the time is into the day (i.e., the number of minutes
-        // since midnight) -- this is done such that we can easily match
-        // time ranges (i.e., whether a particular `time` occurs between
-        // two other times)
-        // Note: all the times in this match statement exclude the upper bound
-        match time.hour() * 60 + time.minute() {
-            // 08:25 to 08:50
-            505..=529 => Some(Period::Tutor),
+        // The hour and minute of a `NaiveTime` always fit in a `u8`
+        Self::from_hm(time.hour() as u8, time.minute() as u8)
+    }
+
+    /// Retrieves the times at which the `Period` starts and ends.
+    ///
+    /// *See the [`crate`] documentation for more information*.
+    ///
+    /// # Remarks
+    ///
+    /// The end time is **not** included in the `Period` (e.g., the end time of
+    /// [`Period::First`] is `09:50`, which belongs to [`Period::Second`]).
+    #[cfg(feature = "chrono")]
+    pub const fn bounds(self) -> (NaiveTime, NaiveTime) {
+        let (_, start, end) = Self::BOUNDARIES[self.index()];
 
-            // 8:50 to 9:50
-            530..=589 => Some(Period::First),
+        (
+            NaiveTime::from_hms_opt(start / 60, start % 60, 0).unwrap(),
+            NaiveTime::from_hms_opt(end / 60, end % 60, 0).unwrap(),
+        )
+    }
 
-            // 9:50 to 10:50
-            590..=649 => Some(Period::Second),
+    /// Retrieves the time at which the `Period` starts.
+    ///
+    /// *See the [`crate`] documentation for more information*.
+    #[cfg(feature = "chrono")]
+    pub const fn start_time(self) -> NaiveTime {
+        self.bounds().0
+    }
 
-            // 10:50 to 11:10
-            650..=669 => Some(Period::Break),
+    /// Retrieves the time at which the `Period` ends.
+    ///
+    /// *See the [`crate`] documentation for more information*.
+    ///
+    /// # Remarks
+    ///
+    /// The end time is **not** included in the `Period` (e.g., the end time of
+    /// [`Period::First`] is `09:50`, which belongs to [`Period::Second`]).
+    #[cfg(feature = "chrono")]
+    pub const fn end_time(self) -> NaiveTime {
+        self.bounds().1
+    }
 
-            // 11:10 to 12:10
-            670..=729 => Some(Period::Third),
+    /// Retrieves the length of the `Period`.
+    ///
+    /// # Remarks
+    ///
+    /// The numbered periods are sixty minutes long, whereas tutor time,
+    /// break, and lunch are `25`, `20`, and `45` minutes long respectively.
+    #[cfg(feature = "chrono")]
+    pub fn duration(self) -> chrono::Duration {
+        let (_, start, end) = Self::BOUNDARIES[self.index()];
 
-            // 12:10 to 13:10
-            730..=789 => Some(Period::Fourth),
+        chrono::Duration::minutes((end - start).into())
+    }
 
-            // 13:10 to 13:55
-            790..=834 => Some(Period::Lunch),
+    /// Creates a `Period` from its WDF token (see the [`crate`]
+    /// documentation), returning [`None`] if the token does not refer to a
+    /// `Period`.
+    fn from_wdf(token: char) -> Option<Self> {
+        use Period::*;
+
+        Some(match token {
+            'T' => Tutor,
+            '1' => First,
+            '2' => Second,
+            'B' => Break,
+            '3' => Third,
+            '4' => Fourth,
+            'L' => Lunch,
+            '5' => Fifth,
 
-            // 13:55 to 14:55
-            835..=894 => Some(Period::Fifth),
+            _ => return None,
+        })
+    }
 
-            // No other times are allocated
-            _ => None,
+    /// Retrieves the WDF token of the `Period` (see the [`crate`]
+    /// documentation).
+    fn wdf(self) -> char {
+        use Period::*;
+
+        match self {
+            Tutor => 'T',
+            First => '1',
+            Second => '2',
+            Break => 'B',
+            Third => '3',
+            Fourth => '4',
+            Lunch => 'L',
+            Fifth => '5',
         }
     }
+}
+
+impl FromPrimitive for Period {
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::from_u64(n.try_into().ok()?)
+    }
 
-    fn with_index(index: usize) -> Option<Self> {
-        Some(match index {
-            0 => Self::Tutor,
-            1 => Self::First,
-            2 => Self::Second,
-            3 => Self::Break,
-            4 => Self::Third,
-            5 => Self::Fourth,
-            6 => Self::Lunch,
-            7 => Self::Fifth,
+    fn from_u64(n: u64) -> Option<Self> {
+        use Period::*;
 
+        Some(match n {
+            0 => Tutor,
+            1 => First,
+            2 => Second,
+            3 => Break,
+            4 => Third,
+            5 => Fourth,
+            6 => Lunch,
+            7 => Fifth,
             _ => return None,
         })
     }
 }
 
+impl ToPrimitive for Period {
+    fn to_i64(&self) -> Option<i64> {
+        Some(*self as i64)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(*self as u64)
+    }
+}
+
+impl From<Period> for usize {
+    /// Retrieves the index of the `Period` in a day (see [`Period::index`]).
+    fn from(value: Period) -> Self {
+        value as usize
+    }
+}
+
+/// An inclusive range of contiguous [teaching periods](Period::is_teaching)
+/// (e.g., the periods of a double lesson).
+///
+/// # Remarks
+///
+/// A `PeriodRange` only contains teaching periods, so it cannot include
+/// [`Period::Tutor`], nor can it span a recess ([`Period::Break`] or
+/// [`Period::Lunch`]).
+///
+/// # Examples
+///
+/// ```
+/// # use timetableau::{Period, PeriodRange};
+/// let double = PeriodRange::new(Period::Third, Period::Fourth).unwrap();
+///
+/// assert!(double.contains(Period::Fourth));
+/// assert!(!double.contains(Period::Fifth));
+///
+/// // The range would span break
+/// assert_eq!(PeriodRange::new(Period::Second, Period::Third), None);
+///
+/// // Tutor time is not a teaching period
+/// assert_eq!(PeriodRange::new(Period::Tutor, Period::First), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PeriodRange {
+    start: Period,
+    end: Period,
+}
+
+impl PeriodRange {
+    /// Creates a new `PeriodRange` from the `start` period to the `end`
+    /// period (inclusive).
+    ///
+    /// Returns [`None`] if `start` is after `end`, or if the range would
+    /// contain a period which is not a teaching period (i.e., if either
+    /// endpoint is not a teaching period, or if the range spans a recess).
+    pub fn new(start: Period, end: Period) -> Option<Self> {
+        if start > end {
+            return None;
+        }
+
+        let range = Self { start, end };
+
+        range
+            .all_periods()
+            .all(Period::is_teaching)
+            .then_some(range)
+    }
+
+    /// Retrieves the first [`Period`] of the `PeriodRange`.
+    pub fn start(self) -> Period {
+        self.start
+    }
+
+    /// Retrieves the last [`Period`] of the `PeriodRange`.
+    pub fn end(self) -> Period {
+        self.end
+    }
+
+    /// Whether the `period` is within the `PeriodRange`.
+    pub fn contains(self, period: Period) -> bool {
+        self.start <= period && period <= self.end
+    }
+
+    /// Retrieves an iterator over every teaching [`Period`] within the
+    /// `PeriodRange` in chronological order.
+    pub fn periods(self) -> impl Iterator<Item = Period> {
+        // A `PeriodRange` only contains teaching periods (see
+        // `PeriodRange::new`)
+        self.all_periods()
+    }
+
+    /// Retrieves an iterator over every [`Period`] from the start to the end
+    /// of the range (inclusive), regardless of whether it is valid.
+    fn all_periods(self) -> impl Iterator<Item = Period> {
+        Period::iter()
+            .skip(self.start.index())
+            .take(self.end.index() - self.start.index() + 1)
+    }
+}
+
 /// A specific timeslot on Highfield's two-week alternating timetable.
 ///
 /// *See the [`crate`] documentation for more information*.
 ///
+/// # Remarks
+///
+/// Fearnhill shares Highfield's timetable structure, so `TimeSlot`s apply to
+/// lessons at both schools.
+///
+/// # Serialisation
+///
+/// By default, a `TimeSlot` is serialised as a structure of its `week`, `day`
+/// and `period` -- if the `serde-index` feature is enabled, it is instead
+/// serialised as its [index](TimeSlot#timeslot-indexes) (a `u8` in the range
+/// `0..=79`), which is considerably more compact.
+///
 /// # TimeSlot Indexes
 ///
 /// Each timeslot is assigned a unique *index*[^1] depending on its
@@ -243,13 +819,22 @@ impl Period {
 ///       iterations (i.e., `I5W1FP5.index()` will be smaller than `I1W2FP5.index()`
 ///       as [`Week::Two`] occurs after [`Week::One`] when compared iteration
 ///       independently).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    all(feature = "serde", not(feature = "serde-index")),
+    derive(Serialize, Deserialize)
+)]
 pub struct TimeSlot {
     pub week: Week,
     pub day: ActiveDay,
     pub period: Period,
 }
 
+// Both schools use a two-week timetable of five active days, each of which
+// contains eight periods -- should Fearnhill's structure ever diverge, a
+// separate type will be required for its timeslots
+const _: () = assert!(TimeSlot::PER_DAY == 8 && TimeSlot::PER_ITERATION == 80);
+
 impl TimeSlot {
     /// The number of `TimeSlot`s per [`ActiveDay`].
     ///
@@ -285,6 +870,17 @@ impl TimeSlot {
     /// It is recommended that you use the normal constructor, or the
     /// [`crate::timeslot!`] macro if you want to hardcode a value, as it makes the
     /// code significantly easier to understand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::{ActiveDay, Period, RangedUsize, TimeSlot, Week};
+    /// let timeslot = TimeSlot::with_index(RangedUsize::new(41).unwrap());
+    ///
+    /// assert_eq!(timeslot.week, Week::Two);
+    /// assert_eq!(timeslot.day, ActiveDay::Monday);
+    /// assert_eq!(timeslot.period, Period::First);
+    /// ```
     pub fn with_index(index: RangedUsize<0, { Self::PER_ITERATION - 1 }>) -> Self {
         // Get the inner value of the RangedU8 -- the reason a RangedU8 is used
         // is to avoid bounds checks (e.g., if a consumer passes an index of
@@ -304,10 +900,64 @@ impl TimeSlot {
             day: ActiveDay::from_usize((index % Self::PER_WEEK) / Self::PER_DAY).unwrap(),
 
             // `index % PER_DAY` = `period_number`
-            period: Period::with_index(index % Self::PER_DAY).unwrap(),
+            period: Period::from_usize(index % Self::PER_DAY).unwrap(),
         }
     }
 
+    /// Create a `TimeSlot` with an index of `index`, without requiring a
+    /// [`RangedUsize`] (see [`TimeSlot::with_index`]).
+    ///
+    /// # Errors
+    ///
+    /// An [`IndexOutOfRangeError`] is returned if the `index` is not less
+    /// than [`TimeSlot::PER_ITERATION`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::{timeslot, TimeSlot};
+    /// assert_eq!(TimeSlot::try_with_index(79), Ok(timeslot!(W2FP5)));
+    /// assert!(TimeSlot::try_with_index(80).is_err());
+    /// ```
+    pub fn try_with_index(index: usize) -> Result<Self, IndexOutOfRangeError> {
+        RangedUsize::new(index)
+            .map(Self::with_index)
+            .ok_or(IndexOutOfRangeError {
+                index,
+                len: Self::PER_ITERATION,
+            })
+    }
+
+    /// Retrieves an iterator over every `TimeSlot` in the timetable in
+    /// chronological order (i.e., in order of their
+    /// [indexes](TimeSlot#timeslot-indexes)).
+    pub fn iter() -> impl ExactSizeIterator<Item = TimeSlot> {
+        (0..Self::PER_ITERATION).map(|index| Self::with_index(RangedUsize::new(index).unwrap()))
+    }
+
+    /// Retrieves an iterator over every `TimeSlot` from `start` to `end`
+    /// (inclusive) in chronological order.
+    ///
+    /// # Remarks
+    ///
+    /// If `start` occurs after `end`, the iterator will be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::{timeslot, TimeSlot};
+    /// let range: Vec<_> = TimeSlot::range(timeslot!(W1MP4), timeslot!(W1TPT)).collect();
+    ///
+    /// assert_eq!(
+    ///     range,
+    ///     [timeslot!(W1MP4), timeslot!(W1MPL), timeslot!(W1MP5), timeslot!(W1TPT)]
+    /// );
+    /// ```
+    pub fn range(start: TimeSlot, end: TimeSlot) -> impl Iterator<Item = TimeSlot> {
+        (start.index()..=end.index())
+            .map(|index| Self::with_index(RangedUsize::new(index).unwrap()))
+    }
+
     /// Creates a new `TimeSlot` based on the `datetime` -- if the `datetime`
     /// takes place during a timeslot's allocated time, that period will be
     /// returned, if the `datetime` does not take place during any timeslot's
@@ -320,7 +970,8 @@ impl TimeSlot {
     /// The reason why a `week` parameter is required is because the
     /// `week` cannot be created using the `datetime` alone -- at the time
     /// of writing, there is no known and reliable way to determine the week
-    /// based on the date alone.
+    /// based on the date alone (although it can be determined from a known
+    /// week one Monday, see [`Week::from_date`]).
     #[cfg(feature = "chrono")]
     pub fn from_datetime<Tz>(week: Week, datetime: DateTime<Tz>) -> Option<Self>
     where
@@ -343,6 +994,120 @@ impl TimeSlot {
         })
     }
 
+    /// Retrieves the date and time at which the `TimeSlot` starts during the
+    /// iteration of the timetable whose week one starts on `week_one_monday`.
+    ///
+    /// # Errors
+    ///
+    /// * [`DateTimeError::NotMonday`] if `week_one_monday` is not a Monday.
+    /// * [`DateTimeError::NonexistentTime`] if the start of the `TimeSlot`
+    ///   does not exist in the `timezone` provided (e.g., because it was
+    ///   skipped by a daylight saving time transition).
+    /// * [`DateTimeError::OutOfRange`] if the date of the `TimeSlot` cannot be
+    ///   represented.
+    ///
+    /// # Remarks
+    ///
+    /// If the start of the `TimeSlot` occurs twice in the `timezone` provided
+    /// (e.g., because the clocks went back), the earliest is used.
+    #[cfg(feature = "chrono")]
+    pub fn to_datetime<Tz>(
+        self,
+        week_one_monday: NaiveDate,
+        timezone: &Tz,
+    ) -> Result<DateTime<Tz>, DateTimeError>
+    where
+        Tz: TimeZone,
+    {
+        self.local_datetime(week_one_monday, self.period.start_time(), timezone)
+    }
+
+    /// Retrieves the date and time at which the `TimeSlot` starts and ends
+    /// during the iteration of the timetable whose week one starts on
+    /// `week_one_monday`.
+    ///
+    /// Like [`Period`]s, the end is exclusive (e.g., a [`Period::First`]
+    /// `TimeSlot` ends at `09:50`, which is the start of [`Period::Second`]).
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`TimeSlot::to_datetime`] are returned (for either
+    /// the start or the end of the `TimeSlot`).
+    ///
+    /// # Remarks
+    ///
+    /// If the start or end of the `TimeSlot` occurs twice in the `timezone`
+    /// provided, the earliest is used.
+    #[cfg(feature = "chrono")]
+    pub fn datetime_range<Tz>(
+        self,
+        week_one_monday: NaiveDate,
+        timezone: &Tz,
+    ) -> Result<(DateTime<Tz>, DateTime<Tz>), DateTimeError>
+    where
+        Tz: TimeZone,
+    {
+        let (start, end) = self.period.bounds();
+
+        Ok((
+            self.local_datetime(week_one_monday, start, timezone)?,
+            self.local_datetime(week_one_monday, end, timezone)?,
+        ))
+    }
+
+    /// Retrieves the date and time at `time` on the day of the `TimeSlot`
+    /// (see [`TimeSlot::to_datetime`]).
+    #[cfg(feature = "chrono")]
+    fn local_datetime<Tz>(
+        self,
+        week_one_monday: NaiveDate,
+        time: NaiveTime,
+        timezone: &Tz,
+    ) -> Result<DateTime<Tz>, DateTimeError>
+    where
+        Tz: TimeZone,
+    {
+        let weekday = week_one_monday.weekday();
+
+        if weekday != Weekday::Mon {
+            return Err(DateTimeError::NotMonday(weekday));
+        }
+
+        // Week two starts seven days after week one
+        let days = self.week as u64 * 7 + self.day.num_days_from_monday() as u64;
+
+        let date = week_one_monday
+            .checked_add_days(chrono::Days::new(days))
+            .ok_or(DateTimeError::OutOfRange)?;
+        let datetime = date.and_time(time);
+
+        timezone
+            .from_local_datetime(&datetime)
+            .earliest()
+            .ok_or(DateTimeError::NonexistentTime(datetime))
+    }
+
+    /// Retrieves the human-readable labels of the components of the
+    /// `TimeSlot` -- the number of its week, the name of its day (see
+    /// [`ActiveDay::name`]) and the label of its period (see
+    /// [`Period::label`]) (e.g., `(2, "Thursday", "Second")` for `W2RP2`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::timeslot;
+    /// assert_eq!(timeslot!(W1MPL).labels(), (1, "Monday", "LUNCH"));
+    /// ```
+    pub fn labels(self) -> (u8, &'static str, &'static str) {
+        // The weeks are numbered from one (and the discriminant of a `Week`
+        // always fits in a `u8`)
+        (
+            self.week.to_u8().unwrap() + 1,
+            self.day.name(),
+            self.period.label(),
+        )
+    }
+
     /// Retrieves the `index` of the `TimeSlot`.
     ///
     /// *See the [period index documentation](TimeSlot#timeslot-indexes) for
@@ -354,35 +1119,243 @@ impl TimeSlot {
     /// result, this method can be used to index an array/vector of [`TimeSlot::PER_ITERATION`]
     /// elements).
     pub fn index(self) -> usize {
-        (self.week as usize) * Self::PER_WEEK
-            + self.day.num_days_from_monday() * Self::PER_DAY
-            + self.period as usize
+        usize::from(self.week) * Self::PER_WEEK
+            + usize::from(self.day) * Self::PER_DAY
+            + usize::from(self.period)
+    }
+
+    /// Retrieves the `TimeSlot` which immediately follows this one, wrapping
+    /// from the last `TimeSlot` of an iteration (`W2FP5`) to the first
+    /// (`W1MPT`).
+    ///
+    /// *See [`TimeSlot::checked_next`] for a non-wrapping alternative*.
+    pub fn next(self) -> Self {
+        let index = (self.index() + 1) % Self::PER_ITERATION;
+
+        Self::with_index(RangedUsize::new(index).unwrap())
+    }
+
+    /// Retrieves the `TimeSlot` which immediately precedes this one, wrapping
+    /// from the first `TimeSlot` of an iteration (`W1MPT`) to the last
+    /// (`W2FP5`).
+    ///
+    /// *See [`TimeSlot::checked_prev`] for a non-wrapping alternative*.
+    pub fn prev(self) -> Self {
+        let index = (self.index() + Self::PER_ITERATION - 1) % Self::PER_ITERATION;
+
+        Self::with_index(RangedUsize::new(index).unwrap())
+    }
+
+    /// Retrieves the `TimeSlot` which immediately follows this one, or
+    /// [`None`] if this is the last `TimeSlot` of an iteration (`W2FP5`).
+    pub fn checked_next(self) -> Option<Self> {
+        RangedUsize::new(self.index() + 1).map(Self::with_index)
+    }
+
+    /// Retrieves the `TimeSlot` which immediately precedes this one, or
+    /// [`None`] if this is the first `TimeSlot` of an iteration (`W1MPT`).
+    pub fn checked_prev(self) -> Option<Self> {
+        RangedUsize::new(self.index().checked_sub(1)?).map(Self::with_index)
+    }
+}
+
+// `TimeSlot`s are ordered chronologically (iteration independently) -- this is
+// done using the index rather than deriving `PartialOrd` and `Ord` such that the
+// ordering does not depend on the order in which the fields are declared
+impl PartialOrd for TimeSlot {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TimeSlot {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index().cmp(&other.index())
+    }
+}
+
+impl Display for TimeSlot {
+    // Format the TimeSlot using its WDF notation (e.g., `W1RP2`)
+    //
+    // See the crate level documentation for more information
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let week = match self.week {
+            Week::One => b'1',
+            Week::Two => b'2',
+        };
+
+        // The notation is written to a buffer such that `pad` can honour the
+        // formatter's width, fill and alignment (e.g., `{:^10}`)
+        let wdf = [
+            b'W',
+            week,
+            self.day.wdf() as u8,
+            b'P',
+            self.period.wdf() as u8,
+        ];
+
+        // Every component of the notation is an ASCII character
+        f.pad(core::str::from_utf8(&wdf).unwrap())
+    }
+}
+
+#[cfg(feature = "serde-index")]
+impl Serialize for TimeSlot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Every index is less than `PER_ITERATION`, so it always fits in a u8
+        serializer.serialize_u8(self.index() as u8)
+    }
+}
+
+#[cfg(feature = "serde-index")]
+impl<'de> Deserialize<'de> for TimeSlot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let index = u8::deserialize(deserializer)?;
+
+        Self::try_with_index(index.into()).map_err(de::Error::custom)
+    }
+}
+
+impl FromStr for TimeSlot {
+    type Err = ParseTimeSlotError;
+
+    /// Parses a `TimeSlot` from its WDF notation (e.g., `W1RP2`).
+    ///
+    /// *See the [`crate`] documentation for more information*.
+    ///
+    /// # Remarks
+    ///
+    /// Like the [`crate::timeslot!`] macro, the WDF notation provided
+    /// **MUST** be uppercase.
+    ///
+    /// Unlike [`TimeSlot::parse_wdf`], which only accepts the canonical form
+    /// of the notation (e.g., `W1MP2`), the following are also accepted:
+    ///
+    /// * The legacy form, in which the day is preceded by a `D` marker (e.g.,
+    ///   `W1DMP2`).
+    /// * An iteration prefix (e.g., `I2W1MP2`), which is ignored as
+    ///   `TimeSlot`s are iteration independent -- *see [`DatedTimeSlot`]
+    ///   for iteration dependent timeslots*.
+    ///
+    /// [`DatedTimeSlot`]: crate::DatedTimeSlot
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with('I') {
+            s.parse::<crate::DatedTimeSlot>().map(|dated| dated.slot)
+        } else {
+            Self::parse_unprefixed(s, true)
+        }
+    }
+}
+
+impl TimeSlot {
+    /// Parses a `TimeSlot` from the canonical form of its WDF notation (e.g.,
+    /// `W1MP2`) -- this is the form produced by the [`Display`]
+    /// implementation and accepted by the [`crate::timeslot!`] macro.
+    ///
+    /// *See the [`crate`] documentation for more information*.
+    ///
+    /// # Remarks
+    ///
+    /// *See the [`FromStr`] implementation for a more lenient parser (which
+    /// also accepts the legacy form and an iteration prefix)*.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::{timeslot, ParseTimeSlotError, TimeSlot};
+    /// assert_eq!(TimeSlot::parse_wdf("W1MP2"), Ok(timeslot!(W1MP2)));
+    /// assert_eq!(
+    ///     TimeSlot::parse_wdf("W1DMP2"),
+    ///     Err(ParseTimeSlotError::InvalidDay('D'))
+    /// );
+    /// ```
+    pub fn parse_wdf(s: &str) -> Result<Self, ParseTimeSlotError> {
+        Self::parse_unprefixed(s, false)
+    }
+
+    /// Parses a `TimeSlot` from its WDF notation without an iteration prefix
+    /// (accepting the legacy form if `legacy` is true).
+    pub(crate) fn parse_unprefixed(s: &str, legacy: bool) -> Result<Self, ParseTimeSlotError> {
+        use ParseTimeSlotError::*;
+
+        let mut chars = s.chars();
+        let mut next = || chars.next().ok_or(UnexpectedEnd);
+
+        match next()? {
+            'W' => {}
+            found => {
+                return Err(ExpectedMarker {
+                    expected: 'W',
+                    found,
+                })
+            }
+        }
+
+        let week = match next()? {
+            '1' => Week::One,
+            '2' => Week::Two,
+            week => return Err(InvalidWeek(week)),
+        };
+
+        // The legacy form of the notation precedes the day with a `D` marker
+        // (no active day is represented by `D`, so this is unambiguous)
+        let day = match next()? {
+            'D' if legacy => next()?,
+            day => day,
+        };
+        let day = ActiveDay::from_wdf(day).ok_or(InvalidDay(day))?;
+
+        match next()? {
+            'P' => {}
+            found => {
+                return Err(ExpectedMarker {
+                    expected: 'P',
+                    found,
+                })
+            }
+        }
+
+        let period = next()?;
+        let period = Period::from_wdf(period).ok_or(InvalidPeriod(period))?;
+
+        // Reject any trailing garbage (e.g., `W1MP12`)
+        if chars.next().is_some() {
+            return Err(TrailingCharacters);
+        }
+
+        Ok(Self { week, day, period })
     }
 }
 
-/// Creates a [`TimeSlot`] from its `WDP` format.
+/// Creates a [`TimeSlot`] from the canonical form of its WDF notation (see
+/// [`TimeSlot::parse_wdf`]).
 ///
 /// *See the [`crate`] documentation for more information*.
 ///
 /// # Examples
 ///
 /// ```
-/// # use timetableau::{Week, TimeSlot, timeslot, Period};
-/// # use chrono::prelude::*;
+/// # use timetableau::{ActiveDay, Week, TimeSlot, timeslot, Period};
 /// #
 /// # fn main() {
 /// // Create the week one thursday second period
 /// let timeslot = timeslot!(W1RP2);
 ///
 /// assert_eq!(timeslot.week, Week::One);
-/// assert_eq!(timeslot.day, Weekday::Thu);
+/// assert_eq!(timeslot.day, ActiveDay::Thursday);
 /// assert_eq!(timeslot.period, Period::Second)
 /// # }
 /// ```
 ///
 /// # Remarks
 ///
-/// The `WDP` format provided **MUST** be uppercase -- lowercase `WDP`s will
+/// The WDF notation provided **MUST** be uppercase -- lowercase notation will
 /// fail to match.
 #[macro_export]
 macro_rules! timeslot {
@@ -631,7 +1604,38 @@ macro_rules! timeslot {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::RangedU8;
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn week_from_date() {
+        let anchor = NaiveDate::from_ymd_opt(2024, 9, 2).unwrap();
+        let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+
+        // The same week
+        assert_eq!(Week::from_date(anchor, anchor), Week::One);
+        assert_eq!(Week::from_date(anchor, date(9, 8)), Week::One);
+
+        // The next week
+        assert_eq!(Week::from_date(anchor, date(9, 9)), Week::Two);
+        assert_eq!(Week::from_date(anchor, date(9, 15)), Week::Two);
+
+        // Two weeks later
+        assert_eq!(Week::from_date(anchor, date(9, 16)), Week::One);
+
+        // Before the anchor
+        assert_eq!(Week::from_date(anchor, date(9, 1)), Week::Two);
+        assert_eq!(Week::from_date(anchor, date(8, 26)), Week::Two);
+        assert_eq!(Week::from_date(anchor, date(8, 25)), Week::One);
+
+        // An anchor which is not a Monday is moved to the Monday of its week
+        let wednesday = date(9, 4);
+
+        assert_eq!(Week::from_date(wednesday, date(9, 2)), Week::One);
+        assert_eq!(Week::from_date(wednesday, date(9, 3)), Week::One);
+        assert_eq!(Week::from_date(wednesday, date(9, 8)), Week::One);
+        assert_eq!(Week::from_date(wednesday, date(9, 9)), Week::Two);
+        assert_eq!(Week::from_date(wednesday, date(9, 1)), Week::Two);
+    }
 
     #[cfg(feature = "chrono")]
     #[test]
@@ -652,6 +1656,19 @@ mod tests {
         assert_eq!(period_upper, Some(Period::Fifth));
     }
 
+    #[test]
+    fn period_from_hm() {
+        assert_eq!(Period::from_hm(8, 25), Some(Period::Tutor));
+        assert_eq!(Period::from_hm(11, 9), Some(Period::Break));
+        assert_eq!(Period::from_hm(11, 10), Some(Period::Third));
+        assert_eq!(Period::from_hm(14, 54), Some(Period::Fifth));
+
+        assert_eq!(Period::from_hm(8, 24), None);
+        assert_eq!(Period::from_hm(14, 55), None);
+        assert_eq!(Period::from_hm(11, 60), None);
+        assert_eq!(Period::from_hm(24, 0), None);
+    }
+
     #[cfg(feature = "chrono")]
     #[test]
     fn period_invalid() {
@@ -660,6 +1677,402 @@ mod tests {
         assert_eq!(period, None);
     }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn period_start_end_times() {
+        use Period::*;
+
+        let expected = [
+            (Tutor, (8, 25), (8, 50)),
+            (First, (8, 50), (9, 50)),
+            (Second, (9, 50), (10, 50)),
+            (Break, (10, 50), (11, 10)),
+            (Third, (11, 10), (12, 10)),
+            (Fourth, (12, 10), (13, 10)),
+            (Lunch, (13, 10), (13, 55)),
+            (Fifth, (13, 55), (14, 55)),
+        ];
+
+        for (period, (start_hour, start_minute), (end_hour, end_minute)) in expected {
+            assert_eq!(
+                period.start_time(),
+                NaiveTime::from_hms_opt(start_hour, start_minute, 0).unwrap()
+            );
+            assert_eq!(
+                period.end_time(),
+                NaiveTime::from_hms_opt(end_hour, end_minute, 0).unwrap()
+            );
+
+            // The start time is included in the period, the end time is not
+            assert_eq!(Period::from_time(period.start_time()), Some(period));
+            assert_ne!(Period::from_time(period.end_time()), Some(period));
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn period_bounds() {
+        const FIRST: (NaiveTime, NaiveTime) = Period::First.bounds();
+
+        assert_eq!(Period::from_time(FIRST.0), Some(Period::First));
+        assert_eq!(Period::from_time(FIRST.1), Some(Period::Second));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn period_duration() {
+        assert_eq!(Period::First.duration().num_minutes(), 60);
+        assert_eq!(Period::Break.duration().num_minutes(), 20);
+        assert_eq!(Period::Lunch.duration().num_minutes(), 45);
+        assert_eq!(Period::Tutor.duration().num_minutes(), 25);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn active_day_from_weekday() {
+        assert_eq!(ActiveDay::try_from(Weekday::Thu), Ok(ActiveDay::Thursday));
+        assert_eq!(
+            ActiveDay::try_from(Weekday::Sat),
+            Err(InactiveDayError(Weekday::Sat))
+        );
+        assert_eq!(
+            InactiveDayError(Weekday::Sun).to_string(),
+            "Sun is not an active day"
+        );
+    }
+
+    #[test]
+    fn period_classification() {
+        use Period::*;
+
+        // (period, is_teaching, is_break, is_tutor)
+        let expected = [
+            (Tutor, false, false, true),
+            (First, true, false, false),
+            (Second, true, false, false),
+            (Break, false, true, false),
+            (Third, true, false, false),
+            (Fourth, true, false, false),
+            (Lunch, false, true, false),
+            (Fifth, true, false, false),
+        ];
+
+        for (period, teaching, recess, tutor) in expected {
+            assert_eq!(period.is_teaching(), teaching);
+            assert_eq!(period.is_break(), recess);
+            assert_eq!(period.is_tutor(), tutor);
+        }
+    }
+
+    #[test]
+    fn period_label() {
+        use Period::*;
+
+        assert_eq!(Tutor.label(), "TUTOR");
+        assert_eq!(First.label(), "First");
+        assert_eq!(Second.label(), "Second");
+        assert_eq!(Break.label(), "BREAK");
+        assert_eq!(Third.label(), "Third");
+        assert_eq!(Fourth.label(), "Fourth");
+        assert_eq!(Lunch.label(), "LUNCH");
+        assert_eq!(Fifth.label(), "Fifth");
+    }
+
+    #[test]
+    fn usize_from() {
+        assert_eq!(usize::from(Week::One), 0);
+        assert_eq!(usize::from(Week::Two), 1);
+
+        for (index, day) in ActiveDay::iter().enumerate() {
+            assert_eq!(usize::from(day), index);
+            assert_eq!(usize::from(day), day.num_days_from_monday());
+        }
+
+        for (index, period) in Period::iter().enumerate() {
+            assert_eq!(usize::from(period), index);
+            assert_eq!(usize::from(period), period.index());
+        }
+    }
+
+    #[test]
+    fn period_boundaries() {
+        for (index, (period, start, end)) in Period::BOUNDARIES.into_iter().enumerate() {
+            assert_eq!(period.index(), index);
+            assert!(start < end);
+        }
+
+        // Each period ends when the next period starts, so the table is
+        // sorted and the periods neither overlap nor leave gaps
+        for pair in Period::BOUNDARIES.windows(2) {
+            let [(_, _, end), (_, next_start, _)] = pair else {
+                unreachable!()
+            };
+
+            assert_eq!(end, next_start);
+        }
+    }
+
+    #[test]
+    fn period_from_index() {
+        assert_eq!(Period::from_index(0), Ok(Period::Tutor));
+        assert_eq!(Period::from_index(7), Ok(Period::Fifth));
+        assert_eq!(
+            Period::from_index(8),
+            Err(IndexOutOfRangeError { index: 8, len: 8 })
+        );
+        assert!(Period::from_index(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn period_ordering() {
+        use Period::*;
+
+        let mut periods = vec![Lunch, First, Fifth, Break, Tutor, Fourth, Second, Third];
+        periods.sort();
+
+        assert_eq!(periods, Period::iter().collect::<Vec<_>>());
+        assert!(Tutor < First && Break < Third && Lunch < Fifth);
+    }
+
+    #[test]
+    fn period_range_valid() {
+        use Period::*;
+
+        let range = PeriodRange::new(First, Second).unwrap();
+
+        assert_eq!(range.start(), First);
+        assert_eq!(range.end(), Second);
+        assert!(range.contains(Second));
+        assert!(!range.contains(Tutor));
+        assert!(!range.contains(Third));
+        assert!(range.periods().eq([First, Second]));
+
+        // A single teaching period is a valid range
+        let range = PeriodRange::new(Fifth, Fifth).unwrap();
+
+        assert!(range.contains(Fifth));
+        assert!(range.periods().eq([Fifth]));
+    }
+
+    #[test]
+    fn period_range_invalid() {
+        use Period::*;
+
+        // Spans a recess
+        assert_eq!(PeriodRange::new(Second, Third), None);
+        assert_eq!(PeriodRange::new(Fourth, Fifth), None);
+        assert_eq!(PeriodRange::new(First, Break), None);
+        assert_eq!(PeriodRange::new(Tutor, Fifth), None);
+
+        // Includes a period which is not a teaching period
+        assert_eq!(PeriodRange::new(Tutor, Second), None);
+        assert_eq!(PeriodRange::new(Tutor, Tutor), None);
+        assert_eq!(PeriodRange::new(Lunch, Lunch), None);
+
+        // Reversed
+        assert_eq!(PeriodRange::new(Fourth, Third), None);
+    }
+
+    #[test]
+    fn period_is_adjacent() {
+        use Period::*;
+
+        assert!(First.is_adjacent(Second));
+        assert!(Third.is_adjacent(Fourth));
+
+        // Tutor time is not adjacent to the first period, as it cannot be
+        // part of a `PeriodRange`
+        assert!(!Tutor.is_adjacent(First));
+        assert_eq!(PeriodRange::new(Tutor, First), None);
+
+        // Separated by break and lunch respectively
+        assert!(!Second.is_adjacent(Third));
+        assert!(!Fourth.is_adjacent(Fifth));
+
+        // Recesses are not adjacent to any period
+        assert!(!Second.is_adjacent(Break));
+        assert!(!Break.is_adjacent(Third));
+        assert!(!Lunch.is_adjacent(Fifth));
+
+        // The other period must immediately follow
+        assert!(!Second.is_adjacent(First));
+        assert!(!First.is_adjacent(First));
+        assert!(!Tutor.is_adjacent(Second));
+    }
+
+    #[test]
+    fn period_next_prev_teaching() {
+        use Period::*;
+
+        assert_eq!(Tutor.next_teaching(), Some(First));
+        assert_eq!(Second.next_teaching(), Some(Third));
+        assert_eq!(Break.next_teaching(), Some(Third));
+        assert_eq!(Fourth.next_teaching(), Some(Fifth));
+        assert_eq!(Fifth.next_teaching(), None);
+
+        assert_eq!(Third.prev_teaching(), Some(Second));
+        assert_eq!(Lunch.prev_teaching(), Some(Fourth));
+        assert_eq!(First.prev_teaching(), None);
+        assert_eq!(Tutor.prev_teaching(), None);
+    }
+
+    #[test]
+    fn period_teaching_periods() {
+        use Period::*;
+
+        let periods: Vec<_> = Period::teaching_periods().collect();
+
+        assert_eq!(periods, [First, Second, Third, Fourth, Fifth]);
+        assert!(!periods.contains(&Break));
+    }
+
+    #[test]
+    fn period_iter() {
+        let periods: Vec<_> = Period::iter().collect();
+
+        assert_eq!(periods.len(), Period::PER_DAY);
+        assert_eq!(periods.first(), Some(&Period::Tutor));
+        assert_eq!(periods.last(), Some(&Period::Fifth));
+
+        // Every index is used exactly once
+        for (index, period) in periods.into_iter().enumerate() {
+            assert_eq!(period.index(), index);
+        }
+
+        assert_eq!(Period::from_usize(Period::PER_DAY), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn active_day_weekday_round_trip() {
+        for day in ActiveDay::ALL {
+            assert_eq!(ActiveDay::try_from(Weekday::from(day)), Ok(day));
+        }
+    }
+
+    #[test]
+    fn active_day_name() {
+        assert_eq!(ActiveDay::Monday.name(), "Monday");
+        assert_eq!(ActiveDay::Wednesday.name(), "Wednesday");
+
+        // The names match the names of the variants
+        for day in ActiveDay::iter() {
+            assert_eq!(day.name(), format!("{day:?}"));
+        }
+    }
+
+    #[test]
+    fn active_day_all() {
+        for (index, day) in ActiveDay::ALL.into_iter().enumerate() {
+            assert_eq!(day.num_days_from_monday(), index);
+        }
+    }
+
+    #[test]
+    fn active_day_iter() {
+        let days: Vec<_> = ActiveDay::iter().collect();
+
+        assert_eq!(days.len(), ActiveDay::PER_WEEK);
+        assert_eq!(days.first(), Some(&ActiveDay::Monday));
+        assert_eq!(days.last(), Some(&ActiveDay::Friday));
+    }
+
+    #[test]
+    fn timeslot_range() {
+        assert_eq!(
+            TimeSlot::range(timeslot!(W1FP5), timeslot!(W2MP1)).collect::<Vec<_>>(),
+            [timeslot!(W1FP5), timeslot!(W2MPT), timeslot!(W2MP1)]
+        );
+        assert_eq!(
+            TimeSlot::range(timeslot!(W1MP1), timeslot!(W1MP1)).collect::<Vec<_>>(),
+            [timeslot!(W1MP1)]
+        );
+        assert_eq!(
+            TimeSlot::range(timeslot!(W1MP1), timeslot!(W1FP5)).count(),
+            TimeSlot::PER_WEEK - 1
+        );
+
+        // Inverted ranges are empty
+        assert_eq!(
+            TimeSlot::range(timeslot!(W1MP2), timeslot!(W1MP1)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn timeslot_iter() {
+        assert_eq!(TimeSlot::iter().len(), TimeSlot::PER_ITERATION);
+        assert!(TimeSlot::iter()
+            .map(|timeslot| timeslot.index())
+            .eq(0..TimeSlot::PER_ITERATION));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn timeslot_to_datetime() {
+        let week_one_monday = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+
+        assert_eq!(
+            timeslot!(W2RP3).to_datetime(week_one_monday, &Utc),
+            Ok(Utc.with_ymd_and_hms(2023, 1, 12, 11, 10, 0).unwrap())
+        );
+        assert_eq!(
+            timeslot!(W1MPT).to_datetime(week_one_monday, &Utc),
+            Ok(Utc.with_ymd_and_hms(2023, 1, 2, 8, 25, 0).unwrap())
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn timeslot_datetime_range() {
+        let week_one_monday = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+
+        assert_eq!(
+            timeslot!(W2MP1).datetime_range(week_one_monday, &Utc),
+            Ok((
+                Utc.with_ymd_and_hms(2023, 1, 9, 8, 50, 0).unwrap(),
+                Utc.with_ymd_and_hms(2023, 1, 9, 9, 50, 0).unwrap()
+            ))
+        );
+        assert_eq!(
+            timeslot!(W1FPL).datetime_range(week_one_monday, &Utc),
+            Ok((
+                Utc.with_ymd_and_hms(2023, 1, 6, 13, 10, 0).unwrap(),
+                Utc.with_ymd_and_hms(2023, 1, 6, 13, 55, 0).unwrap()
+            ))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn timeslot_to_datetime_not_monday() {
+        let sunday = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+
+        assert_eq!(
+            timeslot!(W1MP1).to_datetime(sunday, &Utc),
+            Err(DateTimeError::NotMonday(Weekday::Sun))
+        );
+    }
+
+    #[test]
+    fn timeslot_next_prev() {
+        assert_eq!(timeslot!(W1MP5).next(), timeslot!(W1TPT));
+        assert_eq!(timeslot!(W1TPT).prev(), timeslot!(W1MP5));
+
+        // Navigation wraps around the end of the iteration
+        assert_eq!(timeslot!(W2FP5).next(), timeslot!(W1MPT));
+        assert_eq!(timeslot!(W1MPT).prev(), timeslot!(W2FP5));
+    }
+
+    #[test]
+    fn timeslot_checked_next_prev() {
+        assert_eq!(timeslot!(W1FP5).checked_next(), Some(timeslot!(W2MPT)));
+        assert_eq!(timeslot!(W2MPT).checked_prev(), Some(timeslot!(W1FP5)));
+
+        assert_eq!(timeslot!(W2FP5).checked_next(), None);
+        assert_eq!(timeslot!(W1MPT).checked_prev(), None);
+    }
+
     #[test]
     fn timeslot_index_valid() {
         let timeslot = TimeSlot::with_index(RangedUsize::new(23).unwrap());
@@ -670,6 +2083,16 @@ mod tests {
         assert_eq!(timeslot.index(), 23);
     }
 
+    #[test]
+    fn timeslot_try_with_index() {
+        assert_eq!(TimeSlot::try_with_index(0), Ok(timeslot!(W1MPT)));
+        assert_eq!(TimeSlot::try_with_index(79), Ok(timeslot!(W2FP5)));
+        assert_eq!(
+            TimeSlot::try_with_index(80),
+            Err(IndexOutOfRangeError { index: 80, len: 80 })
+        );
+    }
+
     #[test]
     fn timeslot_index_boundary() {
         let timeslot_lower = TimeSlot::with_index(RangedUsize::new(0).unwrap());
@@ -739,6 +2162,197 @@ mod tests {
         assert_eq!(timeslot, None);
     }
 
+    #[test]
+    fn timeslot_parse_valid() {
+        assert_eq!("W1RP2".parse::<TimeSlot>(), Ok(timeslot!(W1RP2)));
+        assert_eq!("W2MPL".parse::<TimeSlot>(), Ok(timeslot!(W2MPL)));
+        assert_eq!("W1MPT".parse::<TimeSlot>(), Ok(timeslot!(W1MPT)));
+        assert_eq!("W2FP5".parse::<TimeSlot>(), Ok(timeslot!(W2FP5)));
+    }
+
+    #[test]
+    fn timeslot_parse_wdf_canonical() {
+        use ParseTimeSlotError::*;
+
+        let timeslot = TimeSlot {
+            week: Week::Two,
+            day: ActiveDay::Thursday,
+            period: Period::Fifth,
+        };
+
+        // The canonical form is produced by `Display` and the macro
+        assert_eq!(timeslot.to_string(), "W2RP5");
+        assert_eq!(timeslot!(W2RP5), timeslot);
+        assert_eq!(TimeSlot::parse_wdf("W2RP5"), Ok(timeslot));
+
+        // The lenient forms are rejected
+        assert_eq!(TimeSlot::parse_wdf("W2DRP5"), Err(InvalidDay('D')));
+        assert_eq!(
+            TimeSlot::parse_wdf("I1W2RP5"),
+            Err(ExpectedMarker {
+                expected: 'W',
+                found: 'I'
+            })
+        );
+
+        for timeslot in TimeSlot::iter() {
+            assert_eq!(TimeSlot::parse_wdf(&timeslot.to_string()), Ok(timeslot));
+        }
+    }
+
+    #[test]
+    fn timeslot_parse_legacy_and_iteration() {
+        // The legacy form
+        assert_eq!("W1DMP2".parse::<TimeSlot>(), Ok(timeslot!(W1MP2)));
+        assert_eq!("W2DRP5".parse::<TimeSlot>(), Ok(timeslot!(W2RP5)));
+
+        // The iteration prefix is ignored
+        assert_eq!("I2W1MP2".parse::<TimeSlot>(), Ok(timeslot!(W1MP2)));
+        assert_eq!("I2W1DMP2".parse::<TimeSlot>(), Ok(timeslot!(W1MP2)));
+        assert_eq!("I10W2FPL".parse::<TimeSlot>(), Ok(timeslot!(W2FPL)));
+
+        assert_eq!(
+            "IW1MP2".parse::<TimeSlot>(),
+            Err(ParseTimeSlotError::InvalidIteration)
+        );
+        assert_eq!(
+            "W1DDMP2".parse::<TimeSlot>(),
+            Err(ParseTimeSlotError::InvalidDay('D'))
+        );
+        assert_eq!(
+            "W1DP2".parse::<TimeSlot>(),
+            Err(ParseTimeSlotError::InvalidDay('P'))
+        );
+    }
+
+    #[test]
+    fn timeslot_parse_invalid() {
+        use ParseTimeSlotError::*;
+
+        assert_eq!("".parse::<TimeSlot>(), Err(UnexpectedEnd));
+        assert_eq!("W1M".parse::<TimeSlot>(), Err(UnexpectedEnd));
+        assert_eq!("W3MP1".parse::<TimeSlot>(), Err(InvalidWeek('3')));
+        assert_eq!("W1SP1".parse::<TimeSlot>(), Err(InvalidDay('S')));
+        assert_eq!("W1MP6".parse::<TimeSlot>(), Err(InvalidPeriod('6')));
+        assert_eq!(
+            "w1mp1".parse::<TimeSlot>(),
+            Err(ExpectedMarker {
+                expected: 'W',
+                found: 'w'
+            })
+        );
+        assert_eq!(
+            "W1MX1".parse::<TimeSlot>(),
+            Err(ExpectedMarker {
+                expected: 'P',
+                found: 'X'
+            })
+        );
+        assert_eq!("W1MP12".parse::<TimeSlot>(), Err(TrailingCharacters));
+    }
+
+    #[test]
+    fn timeslot_display_days() {
+        assert_eq!(timeslot!(W1MP1).to_string(), "W1MP1");
+        assert_eq!(timeslot!(W1TP2).to_string(), "W1TP2");
+        assert_eq!(timeslot!(W2WP3).to_string(), "W2WP3");
+        assert_eq!(timeslot!(W2RP4).to_string(), "W2RP4");
+        assert_eq!(timeslot!(W1FP5).to_string(), "W1FP5");
+    }
+
+    #[test]
+    fn timeslot_display_recesses() {
+        assert_eq!(timeslot!(W1MPT).to_string(), "W1MPT");
+        assert_eq!(timeslot!(W2RPB).to_string(), "W2RPB");
+        assert_eq!(timeslot!(W2FPL).to_string(), "W2FPL");
+    }
+
+    #[cfg(feature = "serde-index")]
+    #[test]
+    fn timeslot_serde_index() {
+        assert_eq!(serde_json::to_string(&timeslot!(W1MPT)).unwrap(), "0");
+        assert_eq!(serde_json::to_string(&timeslot!(W2FP5)).unwrap(), "79");
+
+        for timeslot in [timeslot!(W1MPT), timeslot!(W2FP5)] {
+            let json = serde_json::to_string(&timeslot).unwrap();
+
+            assert_eq!(serde_json::from_str::<TimeSlot>(&json).unwrap(), timeslot);
+        }
+
+        assert!(serde_json::from_str::<TimeSlot>("80").is_err());
+        assert!(serde_json::from_str::<TimeSlot>("-1").is_err());
+    }
+
+    #[test]
+    fn timeslot_labels() {
+        let labels: (u8, &str, &str) = timeslot!(W2RP2).labels();
+
+        assert_eq!(labels, (2, "Thursday", "Second"));
+        assert_eq!(timeslot!(W1MPT).labels(), (1, "Monday", "TUTOR"));
+        assert_eq!(timeslot!(W1FPB).labels(), (1, "Friday", "BREAK"));
+
+        for timeslot in TimeSlot::iter() {
+            let (_, day, period) = timeslot.labels();
+
+            assert_eq!(day, timeslot.day.name());
+            assert_eq!(period, timeslot.period.label());
+        }
+    }
+
+    #[test]
+    fn timeslot_display_padding() {
+        assert_eq!(format!("{:^9}", timeslot!(W1MP2)), "  W1MP2  ");
+        assert_eq!(format!("{:>7}", timeslot!(W2FPL)), "  W2FPL");
+        assert_eq!(format!("{:*<6}", timeslot!(W2RPT)), "W2RPT*");
+    }
+
+    #[test]
+    fn timeslot_display_round_trip() {
+        for index in 0..TimeSlot::PER_ITERATION {
+            let timeslot = TimeSlot::with_index(RangedUsize::new(index).unwrap());
+
+            assert_eq!(timeslot.to_string().parse::<TimeSlot>(), Ok(timeslot));
+        }
+    }
+
+    #[test]
+    fn timeslot_ordering() {
+        let mut timeslots = vec![
+            timeslot!(W2FP5),
+            timeslot!(W1TP3),
+            timeslot!(W2MPT),
+            timeslot!(W1MPT),
+            timeslot!(W1RPL),
+        ];
+
+        timeslots.sort();
+
+        assert!(timeslot!(W1MPT) < timeslot!(W2FP5));
+        assert_eq!(
+            timeslots,
+            [
+                timeslot!(W1MPT),
+                timeslot!(W1TP3),
+                timeslot!(W1RPL),
+                timeslot!(W2MPT),
+                timeslot!(W2FP5),
+            ]
+        );
+    }
+
+    #[test]
+    fn timeslot_hash() {
+        use std::collections::HashMap;
+
+        let mut activities = HashMap::new();
+        activities.insert(timeslot!(W1MPT), "Registration");
+        activities.insert(timeslot!(W2RP3), "Maths");
+
+        assert_eq!(activities.get(&timeslot!(W1MPT)), Some(&"Registration"));
+        assert_eq!(activities.get(&timeslot!(W2RP3)), Some(&"Maths"));
+        assert_eq!(activities.get(&timeslot!(W2RP4)), None);
+    }
+
     #[test]
     fn macro_valid() {
         let timeslot = timeslot!(W2RP3);
@@ -747,4 +2361,51 @@ mod tests {
         assert_eq!(timeslot.day, ActiveDay::Thursday);
         assert_eq!(timeslot.period, Period::Third);
     }
+
+    #[test]
+    fn week_other() {
+        let mut week = Week::One;
+
+        assert_eq!(week.other(), Week::Two);
+        assert_eq!(Week::Two.other(), Week::One);
+
+        week.toggle();
+        assert_eq!(week, Week::Two);
+        week.toggle();
+        assert_eq!(week, Week::One);
+    }
+
+    #[test]
+    fn week_from_to_primitive() {
+        assert_eq!(Week::from_u8(0), Some(Week::One));
+        assert_eq!(Week::from_u8(1), Some(Week::Two));
+        assert_eq!(Week::from_u8(2), None);
+        assert_eq!(Week::from_i32(-1), None);
+
+        assert_eq!(Week::Two.to_u8(), Some(1));
+    }
+
+    #[test]
+    fn period_from_to_primitive() {
+        assert_eq!(Period::from_u8(0), Some(Period::Tutor));
+        assert_eq!(Period::from_u8(7), Some(Period::Fifth));
+        assert_eq!(Period::from_u8(8), None);
+        assert_eq!(Period::from_i64(-1), None);
+
+        for period in Period::iter() {
+            assert_eq!(Period::from_u8(period.to_u8().unwrap()), Some(period));
+        }
+    }
+
+    #[test]
+    fn period_with_index() {
+        assert_eq!(Period::with_index(RangedU8::new(3).unwrap()), Period::Break);
+        assert_eq!(Period::Break.index(), 3);
+
+        for period in Period::iter() {
+            let index = RangedU8::new(period.index() as u8).unwrap();
+
+            assert_eq!(Period::with_index(index), period);
+        }
+    }
 }
@@ -0,0 +1,1428 @@
+use crate::ConflictError;
+#[cfg(feature = "chrono")]
+use crate::Exception;
+#[cfg(feature = "csv")]
+use crate::ImportError;
+#[cfg(feature = "json")]
+use crate::JsonError;
+use crate::Period;
+use crate::{ActiveDay, Activity, RangedUsize, Subject, TimeSlot, Week};
+#[cfg(feature = "json")]
+use alloc::borrow::Cow;
+#[cfg(feature = "serde")]
+use alloc::string::{String, ToString};
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "csv")]
+use chrono::NaiveTime;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone};
+use core::fmt::{self, Display, Formatter};
+use core::{iter, slice};
+#[cfg(feature = "serde")]
+use serde::de::{self, MapAccess, Visitor};
+#[cfg(feature = "serde")]
+use serde::ser::SerializeMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "csv")]
+use std::io::Read;
+
+/// A two-week alternating timetable which assigns (at most) one [`Activity`]
+/// to each [`TimeSlot`].
+///
+/// *See the [`crate`] documentation for more information*.
+///
+/// # Serialisation
+///
+/// A `Timetable` is serialised as a map from the [WDF notation](crate#wdf-notation)
+/// of each assigned [`TimeSlot`] to its [`Activity`] -- unassigned
+/// [`TimeSlot`]s are omitted (and are left unassigned when deserialising):
+///
+/// ```json
+/// {"W1MPT":{"type":"registration"},"W2FP5":{"type":"homeStudy"}}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timetable {
+    // Each activity is stored at the index of its timeslot such that lookups
+    // do not require a search
+    activities: [Option<Activity>; TimeSlot::PER_ITERATION],
+}
+
+impl Timetable {
+    /// Creates a timetable in which no [`TimeSlot`] has been assigned an
+    /// [`Activity`] (equivalent to [`Timetable::default`]).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retrieves the [`Activity`] assigned to the `slot`, or [`None`] if no
+    /// [`Activity`] has been assigned.
+    pub fn get(&self, slot: TimeSlot) -> Option<&Activity> {
+        self.activities[slot.index()].as_ref()
+    }
+
+    /// Assigns the `activity` to the `slot`, replacing any [`Activity`]
+    /// previously assigned to it.
+    pub fn set(&mut self, slot: TimeSlot, activity: Activity) {
+        self.activities[slot.index()] = Some(activity);
+    }
+
+    /// Removes the [`Activity`] assigned to the `slot` (if any).
+    pub fn clear(&mut self, slot: TimeSlot) {
+        self.activities[slot.index()] = None;
+    }
+
+    /// Copies every [`Activity`] (or lack thereof) assigned in the `from`
+    /// [`Week`] to the corresponding [`TimeSlot`] in the `to` [`Week`],
+    /// replacing any [`Activity`] previously assigned in the `to` [`Week`].
+    pub fn copy_week(&mut self, from: Week, to: Week) {
+        for slot in TimeSlot::iter().filter(|slot| slot.week == from) {
+            let target = TimeSlot { week: to, ..slot };
+
+            self.activities[target.index()] = self.activities[slot.index()].clone();
+        }
+    }
+
+    /// Creates a copy of the timetable in which every [`Activity`] is
+    /// assigned `n` [`TimeSlot`]s later (see [`TimeSlot::index`]).
+    ///
+    /// # Remarks
+    ///
+    /// The shift is purely index arithmetic: an [`Activity`] is moved across
+    /// recesses and from one day (or week) into the next as though the
+    /// [`TimeSlot`]s were a single sequence (e.g., shifting `W1MP5` forward
+    /// by one moves it to `W1TPT`). Activities which would be moved past the
+    /// last [`TimeSlot`] of an iteration (`W2FP5`) are dropped rather than
+    /// wrapped around.
+    pub fn shift_forward(&self, n: usize) -> Timetable {
+        let mut shifted = Timetable::default();
+
+        if let Some(kept) = TimeSlot::PER_ITERATION.checked_sub(n) {
+            shifted.activities[n..].clone_from_slice(&self.activities[..kept]);
+        }
+
+        shifted
+    }
+
+    /// Retrieves an iterator over every assigned [`Activity`] (and the
+    /// [`TimeSlot`] to which it is assigned) in chronological order.
+    ///
+    /// # Remarks
+    ///
+    /// [`TimeSlot`]s which have not been assigned an [`Activity`] are
+    /// skipped.
+    pub fn iter(&self) -> TimetableIter<'_> {
+        TimetableIter {
+            activities: self.activities.iter().enumerate(),
+        }
+    }
+
+    /// Retrieves every [`TimeSlot`] (in chronological order) whose assigned
+    /// [`Activity`] differs between this timetable and the `other`
+    /// timetable, alongside the [`Activity`] assigned in this timetable and
+    /// the [`Activity`] assigned in the `other` timetable respectively.
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a Timetable,
+    ) -> Vec<(TimeSlot, Option<&'a Activity>, Option<&'a Activity>)> {
+        TimeSlot::iter()
+            .map(|slot| (slot, self.get(slot), other.get(slot)))
+            .filter(|(_, old, new)| old != new)
+            .collect()
+    }
+
+    /// Retrieves every teaching [`TimeSlot`] (see [`Period::is_teaching`])
+    /// on the `day` of the `week` (in chronological order) in which the
+    /// student is free -- i.e., one which has not been assigned an
+    /// [`Activity`], or which has been assigned [`Activity::SchoolStudy`] or
+    /// [`Activity::HomeStudy`].
+    pub fn free_slots(&self, week: Week, day: ActiveDay) -> Vec<TimeSlot> {
+        Period::teaching_periods()
+            .map(|period| TimeSlot { week, day, period })
+            .filter(|slot| {
+                matches!(
+                    self.get(*slot),
+                    None | Some(Activity::SchoolStudy | Activity::HomeStudy)
+                )
+            })
+            .collect()
+    }
+
+    /// Whether the student is free for the whole of the `day` of the `week`
+    /// -- i.e., whether no [`TimeSlot`] of the day (including tutor time,
+    /// break and lunch) has been assigned a [`Lesson`](Activity::Lesson), and
+    /// every other [`TimeSlot`] is either unassigned or has been assigned
+    /// study ([`Activity::SchoolStudy`] or [`Activity::HomeStudy`]),
+    /// [`Activity::Registration`], [`Activity::Break`] or [`Activity::None`].
+    ///
+    /// # Remarks
+    ///
+    /// A day which has been assigned [`Activity::Registration`] or
+    /// [`Activity::Break`] is still free, as neither requires the student to
+    /// be in school (if they have no lessons). However, a day which has been
+    /// assigned [`Activity::Unknown`] or a
+    /// [miscellaneous activity](Activity::Miscellaneous) is not free, as the
+    /// student may be required to attend it.
+    pub fn is_day_free(&self, week: Week, day: ActiveDay) -> bool {
+        Period::iter().all(|period| {
+            matches!(
+                self.get(TimeSlot { week, day, period }),
+                None | Some(
+                    Activity::SchoolStudy
+                        | Activity::HomeStudy
+                        | Activity::Registration
+                        | Activity::Break
+                        | Activity::None
+                )
+            )
+        })
+    }
+
+    /// Retrieves every day (in chronological order) on which the student is
+    /// free (see [`Timetable::is_day_free`]).
+    pub fn free_days(&self) -> Vec<(Week, ActiveDay)> {
+        [Week::One, Week::Two]
+            .into_iter()
+            .flat_map(|week| ActiveDay::iter().map(move |day| (week, day)))
+            .filter(|(week, day)| self.is_day_free(*week, *day))
+            .collect()
+    }
+
+    /// Counts the teaching [`TimeSlot`]s (see [`Period::is_teaching`]) in the
+    /// `week` which have been assigned a [`Lesson`](Activity::Lesson).
+    pub fn teaching_count(&self, week: Week) -> usize {
+        self.iter()
+            .filter(|(slot, activity)| {
+                slot.week == week
+                    && slot.period.is_teaching()
+                    && matches!(activity, Activity::Lesson { .. })
+            })
+            .count()
+    }
+
+    /// Retrieves every [`TimeSlot`] (in chronological order) which has been
+    /// assigned a [`Lesson`](Activity::Lesson) of the `subject`.
+    pub fn slots_for_subject(&self, subject: &Subject) -> Vec<TimeSlot> {
+        self.iter()
+            .filter(|(_, activity)| activity.subject() == Some(subject))
+            .map(|(slot, _)| slot)
+            .collect()
+    }
+
+    /// Retrieves every pair of consecutive [`TimeSlot`]s (in chronological
+    /// order) in which a lesson at one school is immediately followed by a
+    /// lesson at the other school.
+    ///
+    /// # Remarks
+    ///
+    /// Students require time to travel between the schools, so a lesson
+    /// at the other school must be preceded by a recess (or a free period).
+    pub fn travel_conflicts(&self) -> Vec<(TimeSlot, TimeSlot)> {
+        TimeSlot::iter()
+            .filter_map(|slot| Some((slot, slot.checked_next()?)))
+            // The last period of one day does not lead into the next day
+            .filter(|(slot, next)| slot.day == next.day)
+            .filter(|(slot, next)| match (self.get(*slot), self.get(*next)) {
+                (
+                    Some(Activity::Lesson { location, .. }),
+                    Some(Activity::Lesson {
+                        location: next_location,
+                        ..
+                    }),
+                ) => location.requires_travel(next_location),
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// Checks the timetable against the school's rules, returning every
+    /// [`RuleViolation`] found (in chronological order).
+    ///
+    /// # Remarks
+    ///
+    /// The rules are not enforced when assigning activities, as students
+    /// frequently break them (see [`Activity::HomeStudy`]) -- consumers who
+    /// wish to enforce them should call this method themselves.
+    ///
+    /// Spans which cross a recess are not checked, as an [`ActivitySpan`]
+    /// cannot be created across a recess.
+    ///
+    /// [`ActivitySpan`]: crate::ActivitySpan
+    pub fn validate(&self) -> Vec<RuleViolation> {
+        let mut violations: Vec<_> = self
+            .iter()
+            .filter(|(slot, activity)| {
+                **activity == Activity::HomeStudy
+                    && matches!(slot.period, Period::First | Period::Second)
+            })
+            .map(|(slot, _)| RuleViolation::HomeStudyInSchoolHours(slot))
+            .chain(
+                self.travel_conflicts()
+                    .into_iter()
+                    .map(|(slot, next)| RuleViolation::TravelWithoutRecess(slot, next)),
+            )
+            .collect();
+
+        // The sort is stable, so violations of different rules at the same
+        // timeslot remain in the order in which the rules are checked
+        violations.sort_by_key(|violation| violation.slots()[0].index());
+        violations
+    }
+}
+
+impl<'a> IntoIterator for &'a Timetable {
+    type Item = (TimeSlot, &'a Activity);
+    type IntoIter = TimetableIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over every assigned [`Activity`] in a [`Timetable`] (and the
+/// [`TimeSlot`] to which it is assigned) in chronological order.
+///
+/// *See [`Timetable::iter`] for more information*.
+#[derive(Debug, Clone)]
+pub struct TimetableIter<'a> {
+    activities: iter::Enumerate<slice::Iter<'a, Option<Activity>>>,
+}
+
+impl<'a> Iterator for TimetableIter<'a> {
+    type Item = (TimeSlot, &'a Activity);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Each activity is stored at the index of its timeslot
+        self.activities.find_map(|(index, activity)| {
+            Some((
+                TimeSlot::with_index(RangedUsize::new(index).unwrap()),
+                activity.as_ref()?,
+            ))
+        })
+    }
+}
+
+/// A violation of the school's rules found in a [`Timetable`].
+///
+/// *See [`Timetable::validate`] for more information*.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleViolation {
+    /// [`Activity::HomeStudy`] was assigned to the [`First`] or [`Second`]
+    /// period of the [`TimeSlot`], in which students must be in school.
+    ///
+    /// [`First`]: Period::First
+    /// [`Second`]: Period::Second
+    HomeStudyInSchoolHours(TimeSlot),
+
+    /// Lessons at different schools were assigned to consecutive
+    /// [`TimeSlot`]s, leaving students no time to travel between them.
+    ///
+    /// *See [`Timetable::travel_conflicts`] for more information*.
+    TravelWithoutRecess(TimeSlot, TimeSlot),
+}
+
+impl RuleViolation {
+    /// Retrieves the [`TimeSlot`]s in which the rule was broken (in
+    /// chronological order).
+    pub fn slots(&self) -> Vec<TimeSlot> {
+        match *self {
+            Self::HomeStudyInSchoolHours(slot) => vec![slot],
+            Self::TravelWithoutRecess(slot, next) => vec![slot, next],
+        }
+    }
+
+    /// Retrieves a description of the rule which was broken.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::HomeStudyInSchoolHours(_) => "home study is not permitted before break",
+            Self::TravelWithoutRecess(..) => {
+                "lessons at different schools must be separated by a recess"
+            }
+        }
+    }
+}
+
+impl Display for RuleViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, slot) in self.slots().iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{slot}")?;
+        }
+
+        write!(f, ": {}", self.message())
+    }
+}
+
+/// A builder for a [`Timetable`] which refuses to overwrite assigned
+/// [`TimeSlot`]s.
+///
+/// # Examples
+///
+/// ```
+/// # use timetableau::{timeslot, Activity, TimetableBuilder};
+/// let mut builder = TimetableBuilder::default();
+///
+/// builder.try_set(timeslot!(W1MPT), Activity::Registration).unwrap();
+///
+/// // W1MPT has already been assigned an activity
+/// assert!(builder.try_set(timeslot!(W1MPT), Activity::Break).is_err());
+///
+/// let timetable = builder.build();
+/// assert_eq!(timetable.get(timeslot!(W1MPT)), Some(&Activity::Registration));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct TimetableBuilder {
+    timetable: Timetable,
+}
+
+impl TimetableBuilder {
+    /// Assigns the `activity` to the `slot`.
+    ///
+    /// # Errors
+    ///
+    /// A [`ConflictError`] is returned (and the `slot` is left unchanged) if
+    /// the `slot` has already been assigned an [`Activity`].
+    pub fn try_set(&mut self, slot: TimeSlot, activity: Activity) -> Result<(), ConflictError> {
+        if let Some(existing) = self.timetable.get(slot) {
+            return Err(ConflictError {
+                slot,
+                existing: existing.clone(),
+            });
+        }
+
+        self.timetable.set(slot, activity);
+        Ok(())
+    }
+
+    /// Finalises the [`Timetable`].
+    pub fn build(self) -> Timetable {
+        self.timetable
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timetable {
+    /// Retrieves the [`Activity`] which takes place at the `datetime` during
+    /// the `week` provided, accounting for any `exceptions`.
+    ///
+    /// # Returns
+    ///
+    /// [`None`] if the `datetime` falls on the date of one of the
+    /// `exceptions`, if it does not take place during a [`TimeSlot`] (see
+    /// [`TimeSlot::from_datetime`]), or if no [`Activity`] has been assigned
+    /// to its [`TimeSlot`].
+    ///
+    /// # Remarks
+    ///
+    /// The timetable is not followed on the date of any [`Exception`] (of
+    /// either [kind](crate::ExceptionKind)), so no [`Activity`] is returned.
+    pub fn resolve<Tz>(
+        &self,
+        week: Week,
+        datetime: DateTime<Tz>,
+        exceptions: &[Exception],
+    ) -> Option<&Activity>
+    where
+        Tz: TimeZone,
+    {
+        let date = datetime.date_naive();
+
+        if exceptions.iter().any(|exception| exception.date == date) {
+            return None;
+        }
+
+        self.get(TimeSlot::from_datetime(week, datetime)?)
+    }
+
+    /// Renders a `week` of the timetable as a Markdown table (in the same
+    /// format as the example timetable in the [`crate`] documentation).
+    ///
+    /// The start time of each [`Period`] is listed down the left column, the
+    /// active days are listed across the top row, and each cell contains the
+    /// formatted [`Activity`] (or nothing if the timeslot is unassigned).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use timetableau::{timeslot, Activity, Timetable, Week};
+    /// let mut timetable = Timetable::new();
+    /// timetable.set(timeslot!(W1MPT), Activity::Registration);
+    ///
+    /// let markdown = timetable.to_markdown(Week::One);
+    /// let mut lines = markdown.lines();
+    ///
+    /// assert_eq!(
+    ///     lines.next(),
+    ///     Some("|       | Monday | Tuesday | Wednesday | Thursday | Friday |")
+    /// );
+    /// assert_eq!(lines.nth(1), Some("| 08:25 | Registration |  |  |  |  |"));
+    /// ```
+    pub fn to_markdown(&self, week: Week) -> String {
+        let mut markdown = String::from("|      ");
+
+        for day in ActiveDay::iter() {
+            markdown.push_str(&format!(" | {}", day.name()));
+        }
+
+        markdown.push_str(" |\n|:-----:|");
+        markdown.push_str(&":-----:|".repeat(ActiveDay::PER_WEEK));
+        markdown.push('\n');
+
+        for period in Period::iter() {
+            markdown.push_str(&format!("| {} |", period.start_time().format("%H:%M")));
+
+            for day in ActiveDay::iter() {
+                let cell = self
+                    .get(TimeSlot { week, day, period })
+                    .map(|activity| activity.to_string())
+                    .unwrap_or_default();
+
+                // Pipes would otherwise be interpreted as the end of the cell
+                markdown.push_str(&format!(" {} |", cell.replace('|', "\\|")));
+            }
+
+            markdown.push('\n');
+        }
+
+        markdown
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Timetable {
+    /// Imports a `week` of a timetable from a CSV grid.
+    ///
+    /// The grid must have a header row containing an empty cell followed by
+    /// the active days in chronological order (`Monday` to `Friday`), and each
+    /// subsequent row must contain the start time of a [`Period`] (e.g.,
+    /// `08:50`) followed by the activity for that period on each active day.
+    ///
+    /// Each cell is parsed as follows:
+    ///
+    /// * An empty cell leaves the timeslot unset.
+    /// * `TUTOR` is a [registration](Activity::Registration), and `BREAK` and
+    ///   `LUNCH` are [breaks](Activity::Break).
+    /// * `<subject> / <class> / <location>` (e.g., `Maths / Smith / P212`) is
+    ///   a [lesson](Activity::Lesson).
+    /// * The formatted names of the other activities (e.g., `Home Study`)
+    ///   are those activities.
+    /// * Any other text is a [miscellaneous activity](Activity::Miscellaneous)
+    ///   (which must meet the constraints of [`Activity::miscellaneous`]).
+    ///
+    /// # Errors
+    ///
+    /// An [`ImportError`] is returned if the CSV is malformed, or if a row or
+    /// cell cannot be parsed (in which case, the error includes its line).
+    pub fn from_csv(reader: impl Read, week: Week) -> Result<Self, ImportError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+
+        let days = ActiveDay::iter().map(ActiveDay::name);
+
+        if !reader.headers()?.iter().skip(1).eq(days) {
+            return Err(ImportError::InvalidHeader);
+        }
+
+        let mut timetable = Self::default();
+
+        for record in reader.records() {
+            let record = record?;
+            let line = record.position().map_or(0, |position| position.line());
+
+            let time = &record[0];
+            let period = NaiveTime::parse_from_str(time, "%H:%M")
+                .ok()
+                .and_then(|time| Period::iter().find(|period| period.start_time() == time))
+                .ok_or_else(|| ImportError::InvalidPeriod {
+                    line,
+                    value: time.to_string(),
+                })?;
+
+            for (day, cell) in ActiveDay::iter().zip(record.iter().skip(1)) {
+                let activity = parse_cell(cell).ok_or_else(|| ImportError::InvalidLesson {
+                    line,
+                    day,
+                    value: cell.to_string(),
+                })?;
+
+                if let Some(activity) = activity {
+                    timetable.set(TimeSlot { week, day, period }, activity);
+                }
+            }
+        }
+
+        Ok(timetable)
+    }
+}
+
+/// Parses a cell of a CSV grid (see [`Timetable::from_csv`]), returning
+/// [`None`] if the cell is an invalid lesson or an invalid miscellaneous
+/// activity.
+#[cfg(feature = "csv")]
+fn parse_cell(cell: &str) -> Option<Option<Activity>> {
+    Some(Some(match cell {
+        "" => return Some(None),
+        "TUTOR" | "Registration" => Activity::Registration,
+        "BREAK" | "LUNCH" | "Break" => Activity::Break,
+        "Independent Study" => Activity::SchoolStudy,
+        "Home Study" => Activity::HomeStudy,
+        "Unknown" => Activity::Unknown,
+        "N/A" => Activity::None,
+
+        // Lesson cells are the only cells which contain a `/`
+        lesson if lesson.contains('/') => {
+            let mut parts = lesson.split('/').map(str::trim);
+            let (subject, class, location) = (parts.next()?, parts.next()?, parts.next()?);
+
+            if parts.next().is_some() {
+                return None;
+            }
+
+            Activity::try_lesson(subject, class, location).ok()?
+        }
+
+        description => Activity::miscellaneous(description.to_string())?,
+    }))
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Timetable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.iter().count()))?;
+
+        for (slot, activity) in self {
+            map.serialize_entry(&slot.to_string(), activity)?;
+        }
+
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Timetable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TimetableVisitor;
+
+        impl<'de> Visitor<'de> for TimetableVisitor {
+            type Value = Timetable;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str("a map from WDF notation to activities")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut timetable = Timetable::default();
+
+                while let Some((key, activity)) = map.next_entry::<String, Activity>()? {
+                    // Only the canonical form is accepted, as it is the only
+                    // form which is serialised (and the lenient forms would
+                    // allow several keys to refer to the same timeslot)
+                    let slot = TimeSlot::parse_wdf(&key).map_err(|error| {
+                        de::Error::custom(format_args!("invalid timeslot `{key}`: {error}"))
+                    })?;
+
+                    if timetable.get(slot).is_some() {
+                        return Err(de::Error::custom(format_args!(
+                            "duplicate timeslot `{key}`"
+                        )));
+                    }
+
+                    timetable.set(slot, activity);
+                }
+
+                Ok(timetable)
+            }
+        }
+
+        deserializer.deserialize_map(TimetableVisitor)
+    }
+}
+
+/// The JSON representation of a [`Timetable`].
+#[cfg(feature = "json")]
+#[derive(Serialize, Deserialize)]
+struct JsonTimetable<'a> {
+    version: Cow<'a, str>,
+
+    // The activities share the serialised form of a `Timetable`, such that
+    // they are sparse and ordered chronologically
+    activities: Cow<'a, Timetable>,
+}
+
+/// The schema `version` of the JSON representation of a [`Timetable`].
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct JsonVersion {
+    // The version is not required to be a string, such that any version
+    // which is not supported can be reported
+    version: Option<serde_json::Value>,
+}
+
+#[cfg(feature = "json")]
+impl Timetable {
+    /// The current version of the JSON representation of a [`Timetable`].
+    pub const JSON_VERSION: &'static str = "2";
+
+    /// Converts the timetable into its JSON representation.
+    ///
+    /// The JSON representation is an object containing the schema `version`
+    /// (see [`Timetable::JSON_VERSION`]) and the assigned `activities`, keyed
+    /// by the [WDF notation](crate#wdf-notation) of their timeslots:
+    ///
+    /// ```json
+    /// {"version":"2","activities":{"W1MPT":{"type":"registration"}}}
+    /// ```
+    pub fn to_json(&self) -> String {
+        let json = JsonTimetable {
+            version: Cow::Borrowed(Self::JSON_VERSION),
+            activities: Cow::Borrowed(self),
+        };
+
+        // Serialisation can only fail if a map has non-string keys
+        serde_json::to_string(&json).expect("a timetable is always serialisable")
+    }
+
+    /// Parses a timetable from its JSON representation (see
+    /// [`Timetable::to_json`]).
+    ///
+    /// # Errors
+    ///
+    /// A [`JsonError`] is returned if the JSON is malformed (including if a
+    /// timeslot is invalid), or if its `version` is not
+    /// [`Timetable::JSON_VERSION`].
+    pub fn from_json(json: &str) -> Result<Self, JsonError> {
+        // The version is checked before the rest of the JSON is parsed, such
+        // that a future schema is never mis-parsed as the current schema
+        let JsonVersion { version } = serde_json::from_str(json)?;
+
+        match version.as_ref().and_then(serde_json::Value::as_str) {
+            Some(Self::JSON_VERSION) => {}
+            version => return Err(JsonError::UnsupportedVersion(version.map(str::to_string))),
+        }
+
+        // The JSON is parsed directly (rather than through a `Value`), such
+        // that duplicate timeslots are not merged before they can be rejected
+        Ok(serde_json::from_str::<JsonTimetable>(json)?
+            .activities
+            .into_owned())
+    }
+}
+
+impl Default for Timetable {
+    // Create a timetable in which no timeslot has been assigned an activity
+    fn default() -> Self {
+        Self {
+            // `Activity` is not `Copy`, so `[None; N]` cannot be used
+            activities: core::array::from_fn(|_| None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timeslot;
+    use crate::{Class, FearnhillRoom, HighfieldRoom, Location};
+
+    /// Creates a maths lesson at the `location` (for tests in which only the
+    /// location of a lesson matters).
+    fn lesson_at(location: impl Into<Location>) -> Activity {
+        Activity::lesson(
+            Subject::new("Maths".to_string()).unwrap(),
+            Class::new("Jones".to_string()).unwrap(),
+            location.into(),
+        )
+    }
+
+    #[test]
+    fn timetable_default_empty() {
+        assert_eq!(Timetable::default().iter().count(), 0);
+        assert_eq!(Timetable::new(), Timetable::default());
+    }
+
+    #[test]
+    fn timetable_get_set_clear() {
+        let mut timetable = Timetable::default();
+
+        timetable.set(timeslot!(W1MPT), Activity::Registration);
+        timetable.set(timeslot!(W2FPL), Activity::Break);
+
+        assert_eq!(
+            timetable.get(timeslot!(W1MPT)),
+            Some(&Activity::Registration)
+        );
+        assert_eq!(timetable.get(timeslot!(W2FPL)), Some(&Activity::Break));
+        assert_eq!(timetable.get(timeslot!(W1MP1)), None);
+
+        timetable.clear(timeslot!(W1MPT));
+
+        assert_eq!(timetable.get(timeslot!(W1MPT)), None);
+    }
+
+    #[test]
+    fn timetable_copy_week() {
+        let mut timetable = Timetable::default();
+
+        timetable.set(timeslot!(W1MPT), Activity::Registration);
+        timetable.set(timeslot!(W1WP3), Activity::SchoolStudy);
+        timetable.set(timeslot!(W1FPL), Activity::Break);
+
+        // Existing activities in the target week are overwritten
+        timetable.set(timeslot!(W2MPT), Activity::HomeStudy);
+        timetable.set(timeslot!(W2TP1), Activity::HomeStudy);
+
+        timetable.copy_week(Week::One, Week::Two);
+
+        for slot in TimeSlot::iter().filter(|slot| slot.week == Week::One) {
+            let target = TimeSlot {
+                week: Week::Two,
+                ..slot
+            };
+
+            assert_eq!(timetable.get(slot), timetable.get(target));
+        }
+
+        // The source week is left unchanged
+        assert_eq!(timetable.iter().count(), 6);
+    }
+
+    #[test]
+    fn timetable_iter() {
+        let mut timetable = Timetable::default();
+
+        timetable.set(timeslot!(W2RP3), Activity::SchoolStudy);
+        timetable.set(timeslot!(W1MPT), Activity::Registration);
+        timetable.set(timeslot!(W1TP1), Activity::HomeStudy);
+
+        assert_eq!(
+            timetable.iter().collect::<Vec<_>>(),
+            [
+                (timeslot!(W1MPT), &Activity::Registration),
+                (timeslot!(W1TP1), &Activity::HomeStudy),
+                (timeslot!(W2RP3), &Activity::SchoolStudy),
+            ]
+        );
+    }
+
+    #[test]
+    fn timetable_diff() {
+        let mut old = Timetable::default();
+
+        old.set(timeslot!(W1MPT), Activity::Registration);
+        old.set(
+            timeslot!(W1MP1),
+            Activity::try_lesson("Maths", "Jones", "P212").unwrap(),
+        );
+
+        assert!(old.diff(&old.clone()).is_empty());
+
+        let mut new = old.clone();
+        let swapped = Activity::try_lesson("Physics", "Smith", "U111").unwrap();
+
+        new.set(timeslot!(W1MP1), swapped.clone());
+
+        assert_eq!(
+            old.diff(&new),
+            [(timeslot!(W1MP1), old.get(timeslot!(W1MP1)), Some(&swapped))]
+        );
+
+        // Removed activities are also reported
+        new.clear(timeslot!(W1MPT));
+
+        assert_eq!(
+            old.diff(&new)[0],
+            (timeslot!(W1MPT), Some(&Activity::Registration), None)
+        );
+    }
+
+    #[test]
+    fn timetable_free_slots() {
+        let mut timetable = Timetable::default();
+        let lesson = Activity::try_lesson("Maths", "Jones", "P212").unwrap();
+
+        timetable.set(timeslot!(W1TPT), Activity::Registration);
+        timetable.set(timeslot!(W1TP1), lesson.clone());
+        timetable.set(timeslot!(W1TP2), Activity::SchoolStudy);
+        timetable.set(timeslot!(W1TPB), Activity::Break);
+        timetable.set(timeslot!(W1TP4), lesson.clone());
+        timetable.set(timeslot!(W1TP5), Activity::HomeStudy);
+
+        // Activities on other days are irrelevant
+        timetable.set(timeslot!(W2TP3), lesson);
+
+        assert_eq!(
+            timetable.free_slots(Week::One, ActiveDay::Tuesday),
+            [timeslot!(W1TP2), timeslot!(W1TP3), timeslot!(W1TP5)]
+        );
+        assert_eq!(
+            timetable.free_slots(Week::Two, ActiveDay::Tuesday),
+            [
+                timeslot!(W2TP1),
+                timeslot!(W2TP2),
+                timeslot!(W2TP4),
+                timeslot!(W2TP5)
+            ]
+        );
+    }
+
+    #[test]
+    fn timetable_free_days() {
+        let mut timetable = Timetable::default();
+        let lesson = Activity::try_lesson("Maths", "Jones", "P212").unwrap();
+
+        // Every day other than W1M and W2F has a lesson
+        for slot in TimeSlot::iter().filter(|slot| slot.period == Period::Third) {
+            timetable.set(slot, lesson.clone());
+        }
+
+        timetable.clear(timeslot!(W1MP3));
+        timetable.clear(timeslot!(W2FP3));
+
+        // Registration, break and study do not prevent a day being free
+        timetable.set(timeslot!(W1MPT), Activity::Registration);
+        timetable.set(timeslot!(W1MPB), Activity::Break);
+        timetable.set(timeslot!(W1MP1), Activity::SchoolStudy);
+        timetable.set(timeslot!(W2FP5), Activity::HomeStudy);
+
+        assert!(timetable.is_day_free(Week::One, ActiveDay::Monday));
+        assert!(!timetable.is_day_free(Week::One, ActiveDay::Tuesday));
+        assert_eq!(
+            timetable.free_days(),
+            [
+                (Week::One, ActiveDay::Monday),
+                (Week::Two, ActiveDay::Friday)
+            ]
+        );
+
+        assert_eq!(Timetable::default().free_days().len(), 10);
+    }
+
+    #[test]
+    fn timetable_is_day_free_all_periods() {
+        let mut timetable = Timetable::default();
+
+        // A lesson outside of the teaching periods prevents the day being free
+        timetable.set(
+            timeslot!(W1MPL),
+            Activity::try_lesson("Maths", "Jones", "P212").unwrap(),
+        );
+
+        // A break during a teaching period does not
+        timetable.set(timeslot!(W1TP2), Activity::Break);
+
+        // Activities which the student may be required to attend do too
+        timetable.set(timeslot!(W1WP3), Activity::Unknown);
+        timetable.set(
+            timeslot!(W1RP4),
+            Activity::Miscellaneous("Driving lesson".to_string()),
+        );
+
+        assert!(!timetable.is_day_free(Week::One, ActiveDay::Monday));
+        assert!(timetable.is_day_free(Week::One, ActiveDay::Tuesday));
+        assert!(!timetable.is_day_free(Week::One, ActiveDay::Wednesday));
+        assert!(!timetable.is_day_free(Week::One, ActiveDay::Thursday));
+    }
+
+    #[test]
+    fn timetable_shift_forward() {
+        let mut timetable = Timetable::default();
+
+        timetable.set(timeslot!(W1MP1), Activity::SchoolStudy);
+        timetable.set(timeslot!(W1MP5), Activity::HomeStudy);
+        timetable.set(timeslot!(W2FPL), Activity::Break);
+
+        let shifted = timetable.shift_forward(1);
+
+        // Recesses and the ends of days are not skipped
+        assert_eq!(shifted.get(timeslot!(W1MP2)), Some(&Activity::SchoolStudy));
+        assert_eq!(shifted.get(timeslot!(W1TPT)), Some(&Activity::HomeStudy));
+        assert_eq!(shifted.get(timeslot!(W2FP5)), Some(&Activity::Break));
+        assert_eq!(shifted.iter().count(), 3);
+
+        // Activities shifted past the end of the iteration are dropped
+        let shifted = timetable.shift_forward(2);
+
+        assert_eq!(shifted.get(timeslot!(W1MPB)), Some(&Activity::SchoolStudy));
+        assert_eq!(shifted.iter().count(), 2);
+
+        assert_eq!(timetable.shift_forward(0), timetable);
+        assert_eq!(
+            timetable.shift_forward(TimeSlot::PER_ITERATION),
+            Timetable::default()
+        );
+        assert_eq!(timetable.shift_forward(usize::MAX), Timetable::default());
+    }
+
+    #[test]
+    fn timetable_into_iter() {
+        let mut timetable = Timetable::default();
+
+        timetable.set(timeslot!(W2FP5), Activity::HomeStudy);
+        timetable.set(timeslot!(W1MPT), Activity::Registration);
+
+        let mut slots = Vec::new();
+
+        for (slot, activity) in &timetable {
+            slots.push((slot, activity.clone()));
+        }
+
+        assert_eq!(
+            slots,
+            [
+                (timeslot!(W1MPT), Activity::Registration),
+                (timeslot!(W2FP5), Activity::HomeStudy),
+            ]
+        );
+    }
+
+    #[test]
+    fn timetable_teaching_count() {
+        let mut timetable = Timetable::default();
+        let lesson = Activity::try_lesson("Maths", "Jones", "P212").unwrap();
+
+        timetable.set(timeslot!(W1MP1), lesson.clone());
+        timetable.set(timeslot!(W1MP2), lesson.clone());
+        timetable.set(timeslot!(W1FP5), lesson.clone());
+        timetable.set(timeslot!(W2TP3), lesson.clone());
+
+        // Lessons outside of teaching periods are not counted
+        timetable.set(timeslot!(W1MPT), lesson);
+
+        // Neither are other activities
+        timetable.set(timeslot!(W1RP1), Activity::SchoolStudy);
+        timetable.set(timeslot!(W1RPB), Activity::Break);
+
+        assert_eq!(timetable.teaching_count(Week::One), 3);
+        assert_eq!(timetable.teaching_count(Week::Two), 1);
+        assert_eq!(Timetable::default().teaching_count(Week::One), 0);
+    }
+
+    #[test]
+    fn timetable_slots_for_subject() {
+        let mut timetable = Timetable::default();
+
+        timetable.set(
+            timeslot!(W2TP1),
+            Activity::try_lesson("Maths", "Jones", "P212").unwrap(),
+        );
+        timetable.set(
+            timeslot!(W1MP3),
+            Activity::try_lesson("English", "Smith", "U111").unwrap(),
+        );
+        timetable.set(
+            timeslot!(W1RP2),
+            Activity::try_lesson("Maths", "Brown", "H101").unwrap(),
+        );
+        timetable.set(timeslot!(W1RP3), Activity::SchoolStudy);
+
+        let maths = Subject::new("Maths".to_string()).unwrap();
+
+        assert_eq!(
+            timetable.slots_for_subject(&maths),
+            [timeslot!(W1RP2), timeslot!(W2TP1)]
+        );
+        assert!(timetable
+            .slots_for_subject(&Subject::new("French".to_string()).unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn timetable_validate() {
+        let mut timetable = Timetable::default();
+
+        timetable.set(timeslot!(W1MP1), Activity::HomeStudy);
+        timetable.set(timeslot!(W1MP3), Activity::HomeStudy);
+        timetable.set(timeslot!(W2FP2), Activity::HomeStudy);
+        timetable.set(timeslot!(W2FP1), Activity::SchoolStudy);
+
+        assert_eq!(
+            timetable.validate(),
+            [
+                RuleViolation::HomeStudyInSchoolHours(timeslot!(W1MP1)),
+                RuleViolation::HomeStudyInSchoolHours(timeslot!(W2FP2)),
+            ]
+        );
+
+        timetable.clear(timeslot!(W1MP1));
+        timetable.clear(timeslot!(W2FP2));
+
+        assert!(timetable.validate().is_empty());
+    }
+
+    #[test]
+    fn timetable_validate_aggregates() {
+        let mut timetable = Timetable::default();
+
+        timetable.set(timeslot!(W2TP2), Activity::HomeStudy);
+        timetable.set(timeslot!(W1MP3), lesson_at(HighfieldRoom::Hall));
+        timetable.set(timeslot!(W1MP4), lesson_at(FearnhillRoom::Gym));
+
+        let violations = timetable.validate();
+
+        assert_eq!(
+            violations,
+            [
+                RuleViolation::TravelWithoutRecess(timeslot!(W1MP3), timeslot!(W1MP4)),
+                RuleViolation::HomeStudyInSchoolHours(timeslot!(W2TP2)),
+            ]
+        );
+        assert_eq!(violations[0].slots(), [timeslot!(W1MP3), timeslot!(W1MP4)]);
+        assert_eq!(
+            violations[0].to_string(),
+            "W1MP3, W1MP4: lessons at different schools must be separated by a recess"
+        );
+        assert_eq!(
+            violations[1].to_string(),
+            "W2TP2: home study is not permitted before break"
+        );
+    }
+
+    #[test]
+    fn timetable_travel_conflicts() {
+        let highfield = lesson_at(HighfieldRoom::Hall);
+        let fearnhill = lesson_at(FearnhillRoom::Gym);
+
+        let mut timetable = Timetable::default();
+
+        // Back-to-back lessons at different schools
+        timetable.set(timeslot!(W1MP1), highfield.clone());
+        timetable.set(timeslot!(W1MP2), fearnhill.clone());
+
+        // Separated by break
+        timetable.set(timeslot!(W1MP3), highfield.clone());
+
+        // Back-to-back lessons at the same school
+        timetable.set(timeslot!(W1TP3), highfield.clone());
+        timetable.set(timeslot!(W1TP4), highfield);
+
+        // Separated by lunch
+        timetable.set(timeslot!(W1TP5), fearnhill.clone());
+
+        // Followed by a free period
+        timetable.set(timeslot!(W1WP1), fearnhill);
+        timetable.set(timeslot!(W1WP2), Activity::SchoolStudy);
+
+        assert_eq!(
+            timetable.travel_conflicts(),
+            [(timeslot!(W1MP1), timeslot!(W1MP2))]
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn timetable_resolve() {
+        use crate::ExceptionKind;
+        use chrono::{NaiveDate, Utc};
+
+        let monday = NaiveDate::from_ymd_opt(2024, 9, 2).unwrap();
+        let datetime = timeslot!(W1MP1).to_datetime(monday, &Utc).unwrap();
+
+        let mut timetable = Timetable::default();
+        timetable.set(timeslot!(W1MP1), Activity::SchoolStudy);
+
+        assert_eq!(
+            timetable.resolve(Week::One, datetime, &[]),
+            Some(&Activity::SchoolStudy)
+        );
+        assert_eq!(timetable.resolve(Week::Two, datetime, &[]), None);
+
+        // An inset day on the date of the activity
+        let inset = Exception::new(monday, ExceptionKind::NoSchool);
+
+        assert_eq!(timetable.resolve(Week::One, datetime, &[inset]), None);
+
+        // Exceptions on other dates are irrelevant
+        let sports_day = Exception::new(monday.succ_opt().unwrap(), ExceptionKind::Modified);
+
+        assert_eq!(
+            timetable.resolve(Week::One, datetime, &[sports_day]),
+            Some(&Activity::SchoolStudy)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn timetable_to_markdown() {
+        let mut timetable = Timetable::new();
+
+        timetable.set(timeslot!(W2WP3), Activity::HomeStudy);
+        timetable.set(
+            timeslot!(W2RP3),
+            Activity::Miscellaneous("A | B".to_string()),
+        );
+        timetable.set(timeslot!(W1WP3), Activity::SchoolStudy);
+
+        let markdown = timetable.to_markdown(Week::Two);
+        let lines: Vec<_> = markdown.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "|       | Monday | Tuesday | Wednesday | Thursday | Friday |"
+        );
+        assert_eq!(
+            lines[1],
+            "|:-----:|:-----:|:-----:|:-----:|:-----:|:-----:|"
+        );
+        assert_eq!(lines[6], "| 11:10 |  |  | Home Study | A \\| B |  |");
+        assert_eq!(lines.len(), 2 + Period::PER_DAY);
+    }
+
+    #[test]
+    fn timetable_builder_conflict() {
+        let mut builder = TimetableBuilder::default();
+
+        assert_eq!(
+            builder.try_set(timeslot!(W1MP1), Activity::HomeStudy),
+            Ok(())
+        );
+        assert_eq!(
+            builder.try_set(timeslot!(W1MP1), Activity::SchoolStudy),
+            Err(ConflictError {
+                slot: timeslot!(W1MP1),
+                existing: Activity::HomeStudy,
+            })
+        );
+        assert_eq!(
+            builder.try_set(timeslot!(W1MP2), Activity::SchoolStudy),
+            Ok(())
+        );
+
+        let timetable = builder.build();
+
+        assert_eq!(timetable.get(timeslot!(W1MP1)), Some(&Activity::HomeStudy));
+        assert_eq!(
+            timetable.get(timeslot!(W1MP2)),
+            Some(&Activity::SchoolStudy)
+        );
+    }
+
+    // The first week of the example timetable from the crate documentation
+    // (with classes and locations for each lesson)
+    #[cfg(feature = "csv")]
+    const EXAMPLE: &str = "\
+,Monday,Tuesday,Wednesday,Thursday,Friday
+08:25,TUTOR,TUTOR,TUTOR,TUTOR,TUTOR
+08:50,English / Smith / HG01,Maths / Jones / P212,English / Smith / HG01,Maths / Jones / P212,French / Dupont / U111
+09:50,History / Brown / H102,R.S. / Green / H103,P.E. / Black / Sports Hall,English / Smith / HG01,History / Brown / H102
+10:50,BREAK,BREAK,BREAK,BREAK,BREAK
+11:10,Maths / Jones / P212,R.M. / Green / H103,R.S. / Green / H103,History / Brown / H102,Physics / White / U201
+12:10,Physics / White / U201,Physics / White / U201,Maths / Jones / P212,French / Dupont / U111,Biology / Grey / FH S13
+13:10,LUNCH,LUNCH,LUNCH,LUNCH,LUNCH
+13:55,P.E. / Black / Sports Hall,Biology / Grey / FH S13,Biology / Grey / FH S13,Chemistry / Gold / U202,Chemistry / Gold / U202
+";
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn timetable_from_csv() {
+        let timetable = Timetable::from_csv(EXAMPLE.as_bytes(), Week::One).unwrap();
+
+        assert_eq!(
+            timetable.get(timeslot!(W1MPT)),
+            Some(&Activity::Registration)
+        );
+        assert_eq!(timetable.get(timeslot!(W1WPL)), Some(&Activity::Break));
+        assert_eq!(
+            timetable.get(timeslot!(W1RP4)),
+            Some(&Activity::try_lesson("French", "Dupont", "U111").unwrap())
+        );
+
+        assert_eq!(
+            timetable.get(timeslot!(W1FP5)),
+            Some(&Activity::try_lesson("Chemistry", "Gold", "U202").unwrap())
+        );
+
+        // Every cell of the grid is assigned, and week two is not touched
+        assert_eq!(timetable.iter().count(), TimeSlot::PER_WEEK);
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn timetable_from_csv_blank() {
+        let grid = ",Monday,Tuesday,Wednesday,Thursday,Friday\n08:50,,Maths / Jones / P212,,,\n";
+        let timetable = Timetable::from_csv(grid.as_bytes(), Week::Two).unwrap();
+
+        // Blank cells (and missing rows) are left unset
+        assert_eq!(
+            timetable.get(timeslot!(W2TP1)),
+            Some(&Activity::try_lesson("Maths", "Jones", "P212").unwrap())
+        );
+        assert_eq!(timetable.get(timeslot!(W2MP1)), None);
+        assert_eq!(timetable.iter().count(), 1);
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn timetable_from_csv_invalid() {
+        let invalid_period = ",Monday,Tuesday,Wednesday,Thursday,Friday\n08:30,,,,,\n";
+        let invalid_lesson = ",Monday,Tuesday,Wednesday,Thursday,Friday\n08:50,,,Maths / Jones,,\n";
+        let invalid_header = ",Tuesday,Monday,Wednesday,Thursday,Friday\n";
+
+        assert!(matches!(
+            Timetable::from_csv(invalid_period.as_bytes(), Week::One),
+            Err(ImportError::InvalidPeriod { line: 2, .. })
+        ));
+        assert!(matches!(
+            Timetable::from_csv(invalid_lesson.as_bytes(), Week::One),
+            Err(ImportError::InvalidLesson {
+                line: 2,
+                day: ActiveDay::Wednesday,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Timetable::from_csv(invalid_header.as_bytes(), Week::One),
+            Err(ImportError::InvalidHeader)
+        ));
+
+        // Miscellaneous activities must be ASCII and at most 64 characters
+        let non_ascii = ",Monday,Tuesday,Wednesday,Thursday,Friday\n08:50,Réunion,,,,\n";
+        let too_long = format!(
+            ",Monday,Tuesday,Wednesday,Thursday,Friday\n08:50,,,,,{}\n",
+            "a".repeat(65)
+        );
+
+        assert!(matches!(
+            Timetable::from_csv(non_ascii.as_bytes(), Week::One),
+            Err(ImportError::InvalidLesson {
+                line: 2,
+                day: ActiveDay::Monday,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Timetable::from_csv(too_long.as_bytes(), Week::One),
+            Err(ImportError::InvalidLesson {
+                line: 2,
+                day: ActiveDay::Friday,
+                ..
+            })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn timetable_serde_sparse() {
+        let mut timetable = Timetable::default();
+
+        timetable.set(
+            timeslot!(W1MP1),
+            Activity::try_lesson("Maths", "Jones", "P212").unwrap(),
+        );
+        timetable.set(
+            timeslot!(W1RP3),
+            Activity::try_lesson("Physics", "Smith", "U111").unwrap(),
+        );
+        timetable.set(
+            timeslot!(W2FP5),
+            Activity::try_lesson("French", "Dupont", "FH L2").unwrap(),
+        );
+
+        let value = serde_json::to_value(&timetable).unwrap();
+        let object = value.as_object().unwrap();
+
+        assert_eq!(object.len(), 3);
+        assert_eq!(
+            object["W1MP1"],
+            serde_json::json!({
+                "type": "lesson",
+                "subject": "Maths",
+                "class": "Jones",
+                "location": "P212"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Timetable>(value).unwrap(),
+            timetable
+        );
+
+        // Invalid WDF keys are rejected
+        assert!(serde_json::from_str::<Timetable>(r#"{"W3MP1":{"type":"break"}}"#).is_err());
+
+        // Only the canonical form is accepted
+        assert!(serde_json::from_str::<Timetable>(r#"{"W1DMP1":{"type":"break"}}"#).is_err());
+        assert!(serde_json::from_str::<Timetable>(r#"{"I7W1MP1":{"type":"break"}}"#).is_err());
+
+        // A timeslot cannot be assigned twice
+        let error = serde_json::from_str::<Timetable>(
+            r#"{"W1MP1":{"type":"break"},"W1MP1":{"type":"homeStudy"}}"#,
+        )
+        .unwrap_err();
+
+        assert!(error.to_string().contains("duplicate timeslot `W1MP1`"));
+        assert_eq!(
+            serde_json::from_str::<Timetable>("{}").unwrap(),
+            Timetable::default()
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn timetable_json_round_trip() {
+        let mut timetable = Timetable::default();
+
+        timetable.set(timeslot!(W1MPT), Activity::Registration);
+        timetable.set(
+            timeslot!(W2FP5),
+            Activity::Miscellaneous("Trip".to_string()),
+        );
+
+        let json = timetable.to_json();
+
+        assert!(json.contains(r#""version":"2""#));
+        assert!(json.contains(r#""W2FP5""#));
+
+        // The activities are ordered chronologically, so the representation
+        // is stable
+        assert_eq!(
+            json,
+            r#"{"version":"2","activities":{"W1MPT":{"type":"registration"},"W2FP5":{"type":"miscellaneous","description":"Trip"}}}"#
+        );
+        assert_eq!(Timetable::from_json(&json).unwrap(), timetable);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn timetable_json_invalid() {
+        assert!(matches!(
+            Timetable::from_json(r#"{"version":"1","activities":{}}"#),
+            Err(JsonError::UnsupportedVersion(Some(version))) if version == "1"
+        ));
+        assert!(matches!(
+            Timetable::from_json(r#"{"activities":{}}"#),
+            Err(JsonError::UnsupportedVersion(None))
+        ));
+        assert!(matches!(
+            Timetable::from_json(r#"{"version":"2","activities":{"W3MP1":{"type":"break"}}}"#),
+            Err(JsonError::Json(error)) if error.to_string().contains("`W3MP1`")
+        ));
+        assert!(matches!(
+            Timetable::from_json(
+                r#"{"version":"2","activities":{"W1MP1":{"type":"break"},"W1DMP1":{"type":"break"}}}"#
+            ),
+            Err(JsonError::Json(error)) if error.to_string().contains("`W1DMP1`")
+        ));
+        assert!(matches!(
+            Timetable::from_json(
+                r#"{"version":"2","activities":{"W1MP1":{"type":"break"},"W1MP1":{"type":"break"}}}"#
+            ),
+            Err(JsonError::Json(error)) if error.to_string().contains("duplicate timeslot")
+        ));
+        assert!(matches!(
+            Timetable::from_json(r#"{"version":"2"}"#),
+            Err(JsonError::Json(_))
+        ));
+    }
+}
@@ -0,0 +1,19 @@
+//! Checks that the core types can be used without the standard library (run
+//! with `cargo test --no-default-features` to check the `no_std` build).
+#![no_std]
+
+use timetableau::{timeslot, ActiveDay, Location, Period, TimeSlot, Week};
+
+#[test]
+fn core_types_without_std() {
+    let slot: TimeSlot = "W2RP3".parse().unwrap();
+
+    assert_eq!(slot, timeslot!(W2RP3));
+    assert_eq!(slot.week, Week::Two);
+    assert_eq!(slot.day, ActiveDay::Thursday);
+    assert_eq!(slot.period, Period::Third);
+
+    let location: Location = "FH S13".parse().unwrap();
+
+    assert!(location.requires_travel(&"P212".parse().unwrap()));
+}